@@ -0,0 +1,94 @@
+use sqlx_core::{
+    from_row::FromRow,
+    query_as::{QueryAs, query_as},
+    sql_str::{AssertSqlSafe, SqlSafeStr},
+};
+
+use crate::{ScyllaDB, ScyllaDBArguments, ScyllaDBError, ScyllaDBRow};
+
+/// Create a query that returns the `limit` rows nearest to `vector` with `ORDER BY column ANN OF ?`.
+///
+/// `sql` is a `SELECT` statement without bind markers, an `ORDER BY` or a `LIMIT` clause.
+/// A trailing `;` is removed, and a statement with an `ORDER BY` or a `LIMIT` clause is rejected.
+/// `column` is inserted into the statement as is, so it must not come from user input.
+///
+/// ```rust,ignore
+/// let items: Vec<Item> = query_ann_as(
+///     "SELECT id, name, embedding FROM items",
+///     "embedding",
+///     [0.1f32, 0.2, 0.3],
+///     10,
+/// )?
+/// .fetch_all(&pool)
+/// .await?;
+/// ```
+pub fn query_ann_as<'q, O>(
+    sql: impl SqlSafeStr,
+    column: &str,
+    vector: impl Into<Vec<f32>>,
+    limit: usize,
+) -> Result<QueryAs<'q, ScyllaDB, O, ScyllaDBArguments>, ScyllaDBError>
+where
+    O: for<'r> FromRow<'r, ScyllaDBRow>,
+{
+    let sql = sql.into_sql_str();
+    let sql = ann_statement(sql.as_str(), column, limit)?;
+
+    Ok(query_as(AssertSqlSafe(sql)).bind(vector.into()))
+}
+
+fn ann_statement(sql: &str, column: &str, limit: usize) -> Result<String, ScyllaDBError> {
+    let sql = sql.trim_end().trim_end_matches(';').trim_end();
+
+    let tokens: Vec<&str> = sql.split_whitespace().collect();
+    let has_limit = tokens
+        .iter()
+        .any(|token| token.eq_ignore_ascii_case("LIMIT"));
+    let has_order_by = tokens.windows(2).any(|tokens| {
+        tokens[0].eq_ignore_ascii_case("ORDER") && tokens[1].eq_ignore_ascii_case("BY")
+    });
+    if has_limit || has_order_by {
+        return Err(ScyllaDBError::InvalidAnnStatement(sql.to_string()));
+    }
+
+    Ok(format!("{sql} ORDER BY {column} ANN OF ? LIMIT {limit}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ScyllaDBError, ann::ann_statement};
+
+    #[test]
+    fn it_can_build_ann_statement() -> Result<(), ScyllaDBError> {
+        assert_eq!(
+            "SELECT id, embedding FROM items ORDER BY embedding ANN OF ? LIMIT 10",
+            ann_statement("SELECT id, embedding FROM items", "embedding", 10)?
+        );
+        assert_eq!(
+            "SELECT id, embedding FROM items ORDER BY embedding ANN OF ? LIMIT 3",
+            ann_statement("SELECT id, embedding FROM items ;\n", "embedding", 3)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_ann_statement_with_limit_or_order_by() {
+        assert!(matches!(
+            ann_statement("SELECT id FROM items LIMIT 5", "embedding", 10),
+            Err(ScyllaDBError::InvalidAnnStatement(_))
+        ));
+        assert!(matches!(
+            ann_statement("select id from items limit 5;", "embedding", 10),
+            Err(ScyllaDBError::InvalidAnnStatement(_))
+        ));
+        assert!(matches!(
+            ann_statement(
+                "SELECT id FROM items WHERE category = 'a' ORDER BY id",
+                "embedding",
+                10
+            ),
+            Err(ScyllaDBError::InvalidAnnStatement(_))
+        ));
+    }
+}
@@ -268,7 +268,11 @@ fn map_arguments(args: AnyArguments) -> ScyllaDBArguments {
         buffer.push(argument);
     }
     let buffer = ScyllaDBArgumentBuffer { buffer };
-    ScyllaDBArguments { types, buffer }
+    ScyllaDBArguments {
+        types,
+        buffer,
+        counter_coercion: false,
+    }
 }
 
 fn map_result(result: ScyllaDBQueryResult) -> AnyQueryResult {
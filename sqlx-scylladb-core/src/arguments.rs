@@ -1,30 +1,35 @@
 use std::{
-    collections::HashMap,
+    fmt::Debug,
     net::IpAddr,
     ops::{Deref, DerefMut},
     sync::Arc,
 };
 
 use scylla::{
-    cluster::metadata::{ColumnType, NativeType},
+    cluster::metadata::{CollectionType, ColumnType, NativeType},
     errors::SerializationError,
     serialize::{
         row::{RowSerializationContext, SerializeRow},
-        value::SerializeValue,
+        value::{
+            BuiltinSerializationError, BuiltinSerializationErrorKind, BuiltinTypeCheckError,
+            MapSerializationErrorKind, MapTypeCheckErrorKind, SerializeValue,
+        },
         writers::{CellWriter, RowWriter, WrittenCellProof},
     },
-    value::{Counter, CqlDate, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid},
+    value::{Counter, CqlDate, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid, CqlVarint},
 };
-use sqlx_core::{arguments::Arguments, encode::Encode, types::Type};
+use sqlx_core::{arguments::Arguments, encode::Encode, error::BoxDynError, types::Type};
 use uuid::Uuid;
 
-use crate::{ScyllaDB, ScyllaDBTypeInfo};
+use crate::{ScyllaDB, ScyllaDBError, ScyllaDBTypeInfo};
 
 /// Implementation of [sqlx::Arguments] for ScyllaDB.
 #[derive(Default)]
 pub struct ScyllaDBArguments {
     pub(crate) types: Vec<ScyllaDBTypeInfo>,
     pub(crate) buffer: ScyllaDBArgumentBuffer,
+    /// Encode integers bound to `counter` columns as counter deltas.
+    pub(crate) counter_coercion: bool,
 }
 
 impl Arguments for ScyllaDBArguments {
@@ -56,6 +61,38 @@ impl Arguments for ScyllaDBArguments {
     }
 }
 
+impl ScyllaDBArguments {
+    /// Format the arguments for statement logs.
+    pub(crate) fn to_log_string(&self, redact: bool) -> String {
+        let values: Vec<String> = self
+            .buffer
+            .iter()
+            .map(|argument| {
+                if redact {
+                    REDACTED.to_string()
+                } else {
+                    format!("{argument:?}")
+                }
+            })
+            .collect();
+
+        format!("[{}]", values.join(", "))
+    }
+
+    /// Splits off the first `at` arguments.
+    pub(crate) fn split_to(&mut self, at: usize) -> ScyllaDBArguments {
+        let at = at.min(self.buffer.len());
+        let types = self.types.drain(..at.min(self.types.len())).collect();
+        let buffer = self.buffer.drain(..at).collect();
+
+        ScyllaDBArguments {
+            types,
+            buffer: ScyllaDBArgumentBuffer { buffer },
+            counter_coercion: self.counter_coercion,
+        }
+    }
+}
+
 impl SerializeRow for ScyllaDBArguments {
     fn serialize(
         &self,
@@ -67,7 +104,22 @@ impl SerializeRow for ScyllaDBArguments {
             if let Some(argument) = self.buffer.get(i) {
                 let cell_writer = writer.make_cell_writer();
                 let typ = column.typ();
-                argument.serialize(typ, cell_writer)?;
+                let counter = argument
+                    .to_counter()
+                    .filter(|_| self.counter_coercion)
+                    .filter(|_| *typ == ColumnType::Native(NativeType::Counter));
+                let result = match counter {
+                    Some(counter) => <_ as SerializeValue>::serialize(&counter, typ, cell_writer),
+                    None => argument.serialize(typ, cell_writer),
+                };
+                result.map_err(|source| {
+                    SerializationError::new(ScyllaDBError::ArgumentEncodeError {
+                        index: i,
+                        column_name: column.name().to_string(),
+                        column_type: typ.clone().into_owned(),
+                        source,
+                    })
+                })?;
             }
         }
 
@@ -178,6 +230,24 @@ pub enum ScyllaDBArgument {
     /// array of `decimal` type.
     #[cfg(feature = "bigdecimal-04")]
     BigDecimalArray(Vec<bigdecimal_04::BigDecimal>),
+    /// `decimal` type implemented with [rust_decimal_1] crate.
+    #[cfg(feature = "rust_decimal-1")]
+    RustDecimal(rust_decimal_1::Decimal),
+    /// array of `decimal` type implemented with [rust_decimal_1] crate.
+    #[cfg(feature = "rust_decimal-1")]
+    RustDecimalArray(Vec<rust_decimal_1::Decimal>),
+    /// delta of `counter` type.
+    Counter(Counter),
+    /// `varint` type.
+    Varint(CqlVarint),
+    /// array of `varint` type.
+    VarintArray(Vec<CqlVarint>),
+    /// `varint` type implemented with [num_bigint_04] crate.
+    #[cfg(feature = "num-bigint-04")]
+    NumBigInt(num_bigint_04::BigInt),
+    /// array of `varint` type implemented with [num_bigint_04] crate.
+    #[cfg(feature = "num-bigint-04")]
+    NumBigIntArray(Vec<num_bigint_04::BigInt>),
     /// `timestamp` type.
     CqlTimestamp(CqlTimestamp),
     /// array of `timestamp` type.
@@ -194,6 +264,12 @@ pub enum ScyllaDBArgument {
     /// array of `timestamp` type implemented with [chrono_04] crate.
     #[cfg(feature = "chrono-04")]
     ChronoDateTimeUTCArray(Vec<chrono_04::DateTime<chrono_04::Utc>>),
+    /// `timestamp` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffTimestamp(jiff_02::Timestamp),
+    /// array of `timestamp` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffTimestampArray(Vec<jiff_02::Timestamp>),
     /// `date` type.
     CqlDate(CqlDate),
     /// array of `date` type.
@@ -210,6 +286,12 @@ pub enum ScyllaDBArgument {
     /// array of `date` type implemented with [chrono_04] crate.
     #[cfg(feature = "chrono-04")]
     ChronoNaiveDateArray(Vec<chrono_04::NaiveDate>),
+    /// `date` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffDate(jiff_02::civil::Date),
+    /// array of `date` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffDateArray(Vec<jiff_02::civil::Date>),
     /// `time` type.
     CqlTime(CqlTime),
     /// array of `time` type.
@@ -226,32 +308,164 @@ pub enum ScyllaDBArgument {
     /// array of `time` type implemented with [chrono_04] crate.
     #[cfg(feature = "chrono-04")]
     ChronoNaiveTimeArray(Vec<chrono_04::NaiveTime>),
+    /// `time` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffTime(jiff_02::civil::Time),
+    /// array of `time` type implemented with [jiff_02] crate.
+    #[cfg(feature = "jiff-02")]
+    JiffTimeArray(Vec<jiff_02::civil::Time>),
     /// any tuple type.
     Tuple(Box<dyn SerializeValue + Send + Sync>),
     /// user-defined type.
     UserDefinedType(Box<dyn SerializeValue + Send + Sync>),
     /// array of user-defined type.
     UserDefinedTypeArray(Vec<Box<dyn SerializeValue + Send + Sync>>),
-    /// map type for `text` and `text`.
-    TextTextMap(HashMap<String, String>),
-    /// map type for `text` and `boolean`.
-    TextBooleanMap(HashMap<String, bool>),
-    /// map type for `text` and `tinyint`.
-    TextTinyIntMap(HashMap<String, i8>),
-    /// map type for `text` and `smallint`.
-    TextSmallIntMap(HashMap<String, i16>),
-    /// map type for `text` and `int`.
-    TextIntMap(HashMap<String, i32>),
-    /// map type for `text` and `bigint`.
-    TextBigIntMap(HashMap<String, i64>),
-    /// map type for `text` and `float`.
-    TextFloatMap(HashMap<String, f32>),
-    /// map type for `text` and `double`.
-    TextDoubleMap(HashMap<String, f64>),
-    /// map type for `text` and `uuid`.
-    TextUuidMap(HashMap<String, Uuid>),
-    /// map type for `text` and `inet`.
-    TextIpAddrMap(HashMap<String, IpAddr>),
+    /// any map type, with each key and value encoded as an argument.
+    Map(Vec<(ScyllaDBArgument, ScyllaDBArgument)>),
+    /// any set type, with each element encoded as an argument.
+    Set(Vec<ScyllaDBArgument>),
+    /// any list of collections or tuples, with each element encoded as an argument.
+    List(Vec<ScyllaDBArgument>),
+}
+
+const REDACTED: &str = "<redacted>";
+
+impl Debug for ScyllaDBArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Unset => f.write_str("UNSET"),
+            Self::Any(_) => f.write_str("<any>"),
+            Self::Boolean(value) => Debug::fmt(value, f),
+            Self::BooleanArray(value) => Debug::fmt(value, f),
+            Self::TinyInt(value) => Debug::fmt(value, f),
+            Self::TinyIntArray(value) => Debug::fmt(value, f),
+            Self::SmallInt(value) => Debug::fmt(value, f),
+            Self::SmallIntArray(value) => Debug::fmt(value, f),
+            Self::Int(value) => Debug::fmt(value, f),
+            Self::IntArray(value) => Debug::fmt(value, f),
+            Self::BigInt(value) => Debug::fmt(value, f),
+            Self::BigIntArray(value) => Debug::fmt(value, f),
+            Self::Float(value) => Debug::fmt(value, f),
+            Self::FloatArray(value) => Debug::fmt(value, f),
+            Self::Double(value) => Debug::fmt(value, f),
+            Self::DoubleArray(value) => Debug::fmt(value, f),
+            Self::Text(value) => Debug::fmt(value, f),
+            Self::TextArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "secrecy-08")]
+            Self::SecretText(_) => f.write_str(REDACTED),
+            #[cfg(feature = "secrecy-08")]
+            Self::SecretTextArray(_) => f.write_str(REDACTED),
+            Self::Blob(value) => Debug::fmt(value, f),
+            Self::BlobArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "secrecy-08")]
+            Self::SecretBlob(_) => f.write_str(REDACTED),
+            #[cfg(feature = "secrecy-08")]
+            Self::SecretBlobArray(_) => f.write_str(REDACTED),
+            Self::Uuid(value) => Debug::fmt(value, f),
+            Self::UuidArray(value) => Debug::fmt(value, f),
+            Self::Timeuuid(value) => Debug::fmt(value, f),
+            Self::TimeuuidArray(value) => Debug::fmt(value, f),
+            Self::IpAddr(value) => Debug::fmt(value, f),
+            Self::IpAddrArray(value) => Debug::fmt(value, f),
+            Self::Duration(value) => Debug::fmt(value, f),
+            Self::DurationArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "bigdecimal-04")]
+            Self::BigDecimal(value) => Debug::fmt(value, f),
+            #[cfg(feature = "bigdecimal-04")]
+            Self::BigDecimalArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "rust_decimal-1")]
+            Self::RustDecimal(value) => Debug::fmt(value, f),
+            #[cfg(feature = "rust_decimal-1")]
+            Self::RustDecimalArray(value) => Debug::fmt(value, f),
+            Self::Counter(value) => Debug::fmt(&value.0, f),
+            Self::Varint(value) => Debug::fmt(value, f),
+            Self::VarintArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "num-bigint-04")]
+            Self::NumBigInt(value) => Debug::fmt(value, f),
+            #[cfg(feature = "num-bigint-04")]
+            Self::NumBigIntArray(value) => Debug::fmt(value, f),
+            Self::CqlTimestamp(value) => Debug::fmt(value, f),
+            Self::CqlTimestampArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::OffsetDateTime(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::OffsetDateTimeArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoDateTimeUTC(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoDateTimeUTCArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimestamp(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimestampArray(value) => Debug::fmt(value, f),
+            Self::CqlTime(value) => Debug::fmt(value, f),
+            Self::CqlTimeArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::Time(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::TimeArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoNaiveTime(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoNaiveTimeArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTime(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimeArray(value) => Debug::fmt(value, f),
+            Self::CqlDate(value) => Debug::fmt(value, f),
+            Self::CqlDateArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::Date(value) => Debug::fmt(value, f),
+            #[cfg(feature = "time-03")]
+            Self::DateArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoNaiveDate(value) => Debug::fmt(value, f),
+            #[cfg(feature = "chrono-04")]
+            Self::ChronoNaiveDateArray(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffDate(value) => Debug::fmt(value, f),
+            #[cfg(feature = "jiff-02")]
+            Self::JiffDateArray(value) => Debug::fmt(value, f),
+            Self::Tuple(_) => f.write_str("<tuple>"),
+            Self::UserDefinedType(_) => f.write_str("<user-defined type>"),
+            Self::UserDefinedTypeArray(_) => f.write_str("<user-defined type array>"),
+            Self::Map(value) => f
+                .debug_map()
+                .entries(value.iter().map(|(key, value)| (key, value)))
+                .finish(),
+            Self::Set(value) => f.debug_set().entries(value).finish(),
+            Self::List(value) => f.debug_list().entries(value).finish(),
+        }
+    }
+}
+
+impl ScyllaDBArgument {
+    /// Encode a value of a collection as an argument.
+    pub(crate) fn encode_element<'q, T>(value: &T) -> Result<Self, BoxDynError>
+    where
+        T: Encode<'q, ScyllaDB>,
+    {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+        let is_null = value.encode_by_ref(&mut buf)?;
+        let argument = match buf.buffer.pop() {
+            Some(argument) if !is_null.is_null() => argument,
+            _ => Self::Null,
+        };
+
+        Ok(argument)
+    }
+
+    /// Return the value as a counter delta if it is an integer.
+    fn to_counter(&self) -> Option<Counter> {
+        match self {
+            Self::TinyInt(value) => Some(Counter(*value as i64)),
+            Self::SmallInt(value) => Some(Counter(*value as i64)),
+            Self::Int(value) => Some(Counter(*value as i64)),
+            Self::BigInt(value) => Some(Counter(*value)),
+            _ => None,
+        }
+    }
 }
 
 impl SerializeValue for ScyllaDBArgument {
@@ -266,40 +480,19 @@ impl SerializeValue for ScyllaDBArgument {
             Self::Unset => Ok(writer.set_unset()),
             Self::Boolean(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::BooleanArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TinyInt(value) => {
-                if ColumnType::Native(NativeType::Counter) == *typ {
-                    <_ as SerializeValue>::serialize(&Counter(*value as i64), typ, writer)
-                } else {
-                    <_ as SerializeValue>::serialize(value, typ, writer)
-                }
-            }
+            Self::TinyInt(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::TinyIntArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::SmallInt(value) => {
-                if ColumnType::Native(NativeType::Counter) == *typ {
-                    <_ as SerializeValue>::serialize(&Counter(*value as i64), typ, writer)
-                } else {
-                    <_ as SerializeValue>::serialize(value, typ, writer)
-                }
-            }
+            Self::SmallInt(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::SmallIntArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::Int(value) => {
-                if ColumnType::Native(NativeType::Counter) == *typ {
-                    <_ as SerializeValue>::serialize(&Counter(*value as i64), typ, writer)
-                } else {
-                    <_ as SerializeValue>::serialize(value, typ, writer)
-                }
-            }
+            Self::Int(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::IntArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::BigInt(value) => {
-                if ColumnType::Native(NativeType::Counter) == *typ {
-                    <_ as SerializeValue>::serialize(&Counter(*value as i64), typ, writer)
-                } else {
-                    <_ as SerializeValue>::serialize(value, typ, writer)
-                }
-            }
+            Self::BigInt(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::BigIntArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::Float(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::FloatArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            Self::FloatArray(value) => {
+                check_vector_dimensions(value.len(), typ)?;
+                <_ as SerializeValue>::serialize(value, typ, writer)
+            }
             Self::Double(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::DoubleArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::Text(value) => <_ as SerializeValue>::serialize(value, typ, writer),
@@ -326,6 +519,26 @@ impl SerializeValue for ScyllaDBArgument {
             Self::BigDecimal(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             #[cfg(feature = "bigdecimal-04")]
             Self::BigDecimalArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            #[cfg(feature = "rust_decimal-1")]
+            Self::RustDecimal(value) => {
+                let value = crate::types::decimal::rust_decimal::to_cql_decimal(value);
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
+            #[cfg(feature = "rust_decimal-1")]
+            Self::RustDecimalArray(value) => {
+                let value: Vec<_> = value
+                    .iter()
+                    .map(crate::types::decimal::rust_decimal::to_cql_decimal)
+                    .collect();
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
+            Self::Counter(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            Self::Varint(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            Self::VarintArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            #[cfg(feature = "num-bigint-04")]
+            Self::NumBigInt(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            #[cfg(feature = "num-bigint-04")]
+            Self::NumBigIntArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::CqlTimestamp(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::CqlTimestampArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             #[cfg(feature = "time-03")]
@@ -340,6 +553,19 @@ impl SerializeValue for ScyllaDBArgument {
             Self::ChronoDateTimeUTCArray(value) => {
                 <_ as SerializeValue>::serialize(value, typ, writer)
             }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimestamp(value) => {
+                let value = crate::types::timestamp::jiff::to_cql_timestamp(value);
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimestampArray(value) => {
+                let value: Vec<_> = value
+                    .iter()
+                    .map(crate::types::timestamp::jiff::to_cql_timestamp)
+                    .collect();
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
             Self::CqlTime(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::CqlTimeArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             #[cfg(feature = "time-03")]
@@ -352,6 +578,19 @@ impl SerializeValue for ScyllaDBArgument {
             Self::ChronoNaiveTimeArray(value) => {
                 <_ as SerializeValue>::serialize(value, typ, writer)
             }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTime(value) => {
+                let value = crate::types::time::jiff::to_cql_time(value);
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffTimeArray(value) => {
+                let value: Vec<_> = value
+                    .iter()
+                    .map(crate::types::time::jiff::to_cql_time)
+                    .collect();
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
             Self::CqlDate(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::CqlDateArray(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             #[cfg(feature = "time-03")]
@@ -364,21 +603,183 @@ impl SerializeValue for ScyllaDBArgument {
             Self::ChronoNaiveDateArray(value) => {
                 <_ as SerializeValue>::serialize(value, typ, writer)
             }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffDate(value) => {
+                let value = crate::types::date::jiff::to_cql_date(value);
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
+            #[cfg(feature = "jiff-02")]
+            Self::JiffDateArray(value) => {
+                let value: Vec<_> = value
+                    .iter()
+                    .map(crate::types::date::jiff::to_cql_date)
+                    .collect();
+                <_ as SerializeValue>::serialize(&value, typ, writer)
+            }
             Self::Tuple(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::UserDefinedType(value) => <_ as SerializeValue>::serialize(value, typ, writer),
             Self::UserDefinedTypeArray(value) => {
                 <_ as SerializeValue>::serialize(value, typ, writer)
             }
-            Self::TextTextMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextBooleanMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextTinyIntMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextSmallIntMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextIntMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextBigIntMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextFloatMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextDoubleMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextUuidMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
-            Self::TextIpAddrMap(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            Self::Map(value) => serialize_map(value, typ, writer),
+            Self::Set(value) => <_ as SerializeValue>::serialize(value, typ, writer),
+            Self::List(value) => <_ as SerializeValue>::serialize(value, typ, writer),
         }
     }
 }
+
+fn serialize_map<'b>(
+    entries: &[(ScyllaDBArgument, ScyllaDBArgument)],
+    typ: &ColumnType,
+    writer: CellWriter<'b>,
+) -> Result<WrittenCellProof<'b>, SerializationError> {
+    let rust_name = std::any::type_name::<ScyllaDBArgument>();
+    let (key_type, value_type) = match typ {
+        ColumnType::Collection {
+            typ: CollectionType::Map(key_type, value_type),
+            ..
+        } => (key_type, value_type),
+        _ => {
+            return Err(SerializationError::new(BuiltinTypeCheckError {
+                rust_name,
+                got: typ.clone().into_owned(),
+                kind: MapTypeCheckErrorKind::NotMap.into(),
+            }));
+        }
+    };
+    let serialization_error = |kind: BuiltinSerializationErrorKind| {
+        SerializationError::new(BuiltinSerializationError {
+            rust_name,
+            got: typ.clone().into_owned(),
+            kind,
+        })
+    };
+
+    let mut builder = writer.into_value_builder();
+    let len: i32 = entries
+        .len()
+        .try_into()
+        .map_err(|_| serialization_error(MapSerializationErrorKind::TooManyElements.into()))?;
+    builder.append_bytes(&len.to_be_bytes());
+    for (key, value) in entries {
+        key.serialize(key_type, builder.make_sub_writer())
+            .map_err(|err| {
+                serialization_error(MapSerializationErrorKind::KeySerializationFailed(err).into())
+            })?;
+        value
+            .serialize(value_type, builder.make_sub_writer())
+            .map_err(|err| {
+                serialization_error(MapSerializationErrorKind::ValueSerializationFailed(err).into())
+            })?;
+    }
+
+    builder
+        .finish()
+        .map_err(|_| serialization_error(BuiltinSerializationErrorKind::SizeOverflow))
+}
+
+fn check_vector_dimensions(len: usize, typ: &ColumnType) -> Result<(), SerializationError> {
+    if let ColumnType::Vector { dimensions, .. } = typ {
+        let expect = *dimensions as usize;
+        if len != expect {
+            return Err(SerializationError::new(
+                ScyllaDBError::VectorDimensionError {
+                    expect,
+                    actual: len,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use scylla::value::Counter;
+    use sqlx_core::arguments::Arguments;
+
+    use crate::{ScyllaDBArgument, ScyllaDBArguments};
+
+    #[test]
+    fn it_can_convert_integers_to_counter() {
+        assert_eq!(Some(Counter(7)), ScyllaDBArgument::TinyInt(7).to_counter());
+        assert_eq!(Some(Counter(7)), ScyllaDBArgument::SmallInt(7).to_counter());
+        assert_eq!(Some(Counter(-7)), ScyllaDBArgument::Int(-7).to_counter());
+        assert_eq!(Some(Counter(7)), ScyllaDBArgument::BigInt(7).to_counter());
+        assert_eq!(None, ScyllaDBArgument::Text(String::from("7")).to_counter());
+    }
+
+    #[test]
+    fn it_keeps_counter_coercion_when_split() -> anyhow::Result<()> {
+        let mut arguments = ScyllaDBArguments {
+            counter_coercion: true,
+            ..Default::default()
+        };
+        arguments.add(1i32).map_err(|e| anyhow::anyhow!(e))?;
+        arguments.add(2i32).map_err(|e| anyhow::anyhow!(e))?;
+
+        let first = arguments.split_to(1);
+        assert!(first.counter_coercion);
+        assert_eq!(1, first.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_format_arguments_for_log() -> anyhow::Result<()> {
+        let mut arguments = ScyllaDBArguments::default();
+        arguments.add(1i64).map_err(|e| anyhow::anyhow!(e))?;
+        arguments.add("Alice").map_err(|e| anyhow::anyhow!(e))?;
+        arguments.add(None::<i32>).map_err(|e| anyhow::anyhow!(e))?;
+
+        assert_eq!(r#"[1, "Alice", NULL]"#, arguments.to_log_string(false));
+        assert_eq!(
+            "[<redacted>, <redacted>, <redacted>]",
+            arguments.to_log_string(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_format_collection_arguments_for_log() -> anyhow::Result<()> {
+        let mut arguments = ScyllaDBArguments::default();
+        arguments
+            .add(BTreeMap::from([(String::from("Alice"), 1i32)]))
+            .map_err(|e| anyhow::anyhow!(e))?;
+        arguments
+            .add(BTreeSet::from([1i64, 2i64]))
+            .map_err(|e| anyhow::anyhow!(e))?;
+        arguments
+            .add(vec![vec![1i32], vec![2i32, 3i32]])
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        assert_eq!(
+            r#"[{"Alice": 1}, {1, 2}, [[1], [2, 3]]]"#,
+            arguments.to_log_string(false)
+        );
+        assert_eq!(
+            "[<redacted>, <redacted>, <redacted>]",
+            arguments.to_log_string(true)
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "secrecy-08")]
+    #[test]
+    fn it_always_redacts_secret_arguments() -> anyhow::Result<()> {
+        let mut arguments = ScyllaDBArguments::default();
+        arguments.add("Alice").map_err(|e| anyhow::anyhow!(e))?;
+        arguments
+            .add(secrecy_08::SecretString::new(String::from("my_password")))
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        assert_eq!(r#"["Alice", <redacted>]"#, arguments.to_log_string(false));
+
+        Ok(())
+    }
+}
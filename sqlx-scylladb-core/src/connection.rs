@@ -1,19 +1,47 @@
+#[cfg(feature = "metrics")]
+mod driver_metrics;
 mod establish;
 mod executor;
 mod transaction;
 
 use std::fmt::Debug;
 
-use scylla::client::caching_session::CachingSession;
-use sqlx_core::{Error, connection::Connection, transaction::Transaction};
+use scylla::{
+    client::{caching_session::CachingSession, session::Session},
+    observability::tracing::TracingInfo,
+    statement::prepared::PreparedStatement,
+};
+use sqlx_core::{
+    Error,
+    common::StatementCache,
+    connection::{Connection, LogSettings},
+    transaction::Transaction,
+};
 
-use crate::{ScyllaDB, ScyllaDBConnectOptions, connection::transaction::ScyllaDBTransaction};
+use uuid::Uuid;
+
+use crate::{
+    ScyllaDB, ScyllaDBConnectOptions, ScyllaDBError, ScyllaDBTransactionOptions,
+    connection::transaction::ScyllaDBTransaction,
+};
+
+#[cfg(feature = "metrics")]
+pub use driver_metrics::ScyllaDBDriverMetrics;
 
 /// Implementation of [sqlx::Connection] for ScyllaDB.
 pub struct ScyllaDBConnection {
     pub(crate) caching_session: CachingSession,
     pub(crate) page_size: i32,
     pub(crate) transaction: Option<ScyllaDBTransaction>,
+    pub(crate) next_transaction_options: Option<ScyllaDBTransactionOptions>,
+    pub(crate) log_settings: LogSettings,
+    pub(crate) redact_parameters: bool,
+    pub(crate) counter_coercion: bool,
+    /// Statements prepared again after their result metadata changed, taking precedence over the [CachingSession] cache.
+    pub(crate) reprepared_statements: StatementCache<PreparedStatement>,
+    /// Statements last returned by the [CachingSession] cache, used to tell its hits from misses.
+    #[cfg(feature = "metrics")]
+    pub(crate) prepared_statements: StatementCache<PreparedStatement>,
 }
 
 impl Debug for ScyllaDBConnection {
@@ -21,6 +49,9 @@ impl Debug for ScyllaDBConnection {
         f.debug_struct("ScyllaDBConnection")
             .field("caching_session", &self.caching_session)
             .field("page_size", &self.page_size)
+            .field("log_settings", &self.log_settings)
+            .field("redact_parameters", &self.redact_parameters)
+            .field("counter_coercion", &self.counter_coercion)
             .finish()
     }
 }
@@ -76,6 +107,42 @@ impl Connection for ScyllaDBConnection {
 }
 
 impl ScyllaDBConnection {
+    /// Return the [CachingSession] of scylla-rust-driver used by this connection.
+    pub fn caching_session(&self) -> &CachingSession {
+        &self.caching_session
+    }
+
+    /// Return the [Session] of scylla-rust-driver used by this connection.
+    pub fn session(&self) -> &Session {
+        self.caching_session.get_session()
+    }
+
+    /// Begin a new transaction committed with a batch configured by the options.
+    ///
+    /// Fails with [ScyllaDBError::NestedTransactionOptions] inside a transaction,
+    /// since a nested transaction shares the batch of the outermost one.
+    pub async fn begin_with_options(
+        &mut self,
+        options: ScyllaDBTransactionOptions,
+    ) -> Result<Transaction<'_, ScyllaDB>, Error> {
+        self.next_transaction_options = Some(options);
+        Transaction::begin(self, None).await
+    }
+
+    /// Retrieve the session and events of a traced request from `system_traces`.
+    ///
+    /// Waits until the trace is complete, since the server writes it asynchronously.
+    pub async fn tracing_info(&self, tracing_id: &Uuid) -> Result<TracingInfo, Error> {
+        let tracing_info = self
+            .caching_session
+            .get_session()
+            .get_tracing_info(tracing_id)
+            .await
+            .map_err(ScyllaDBError::from)?;
+
+        Ok(tracing_info)
+    }
+
     #[cfg(feature = "migrate")]
     pub(crate) fn get_keyspace(&self) -> Option<String> {
         self.caching_session
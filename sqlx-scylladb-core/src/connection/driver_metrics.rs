@@ -0,0 +1,49 @@
+use crate::ScyllaDBConnection;
+
+/// A snapshot of the metrics collected by scylla-rust-driver for the session of a connection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScyllaDBDriverMetrics {
+    /// The number of unpaged requests.
+    pub requests_unpaged_num: u64,
+    /// The number of manually paged requests.
+    pub requests_manually_paged_num: u64,
+    /// The number of errors in unpaged requests.
+    pub errors_unpaged_num: u64,
+    /// The number of errors in manually paged requests.
+    pub errors_manually_paged_num: u64,
+    /// The number of retries.
+    pub retries_num: u64,
+    /// The average latency in milliseconds, if any request has been measured.
+    pub latency_avg_ms: Option<u64>,
+    /// The 99th percentile latency in milliseconds, if any request has been measured.
+    pub latency_p99_ms: Option<u64>,
+    /// The mean rate of requests per second.
+    pub mean_rate: f64,
+    /// The number of active connections.
+    pub total_connections: u64,
+    /// The number of connection timeouts.
+    pub connection_timeouts: u64,
+    /// The number of request timeouts.
+    pub request_timeouts: u64,
+}
+
+impl ScyllaDBConnection {
+    /// Take a snapshot of the metrics collected by scylla-rust-driver.
+    pub fn driver_metrics(&self) -> ScyllaDBDriverMetrics {
+        let metrics = self.caching_session.get_session().get_metrics();
+
+        ScyllaDBDriverMetrics {
+            requests_unpaged_num: metrics.get_requests_unpaged_num(),
+            requests_manually_paged_num: metrics.get_requests_manually_paged_num(),
+            errors_unpaged_num: metrics.get_errors_unpaged_num(),
+            errors_manually_paged_num: metrics.get_errors_manually_paged_num(),
+            retries_num: metrics.get_retries_num(),
+            latency_avg_ms: metrics.get_latency_avg_ms().ok(),
+            latency_p99_ms: metrics.get_latency_percentile_ms(99.0).ok(),
+            mean_rate: metrics.get_mean_rate(),
+            total_connections: metrics.get_total_connections(),
+            connection_timeouts: metrics.get_connection_timeouts(),
+            request_timeouts: metrics.get_request_timeouts(),
+        }
+    }
+}
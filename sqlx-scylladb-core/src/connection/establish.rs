@@ -1,7 +1,7 @@
 use scylla::client::{
     caching_session::CachingSessionBuilder, session::TlsContext, session_builder::SessionBuilder,
 };
-use sqlx_core::Error;
+use sqlx_core::{Error, common::StatementCache};
 
 use crate::{ScyllaDBConnectOptions, ScyllaDBConnection, ScyllaDBError};
 
@@ -28,10 +28,7 @@ impl ScyllaDBConnection {
             builder = builder.tcp_nodelay(true);
         }
 
-        let session = builder
-            .build()
-            .await
-            .map_err(ScyllaDBError::NewSessionError)?;
+        let session = builder.build().await.map_err(ScyllaDBError::from)?;
 
         if let Some(keyspace) = &options.keyspace {
             session
@@ -48,6 +45,13 @@ impl ScyllaDBConnection {
             caching_session: session,
             page_size: options.page_size,
             transaction: None,
+            next_transaction_options: None,
+            log_settings: options.log_settings.clone(),
+            redact_parameters: options.redact_parameters,
+            counter_coercion: options.counter_coercion,
+            reprepared_statements: StatementCache::new(options.statement_cache_capacity),
+            #[cfg(feature = "metrics")]
+            prepared_statements: StatementCache::new(options.statement_cache_capacity),
         };
 
         Ok(conn)
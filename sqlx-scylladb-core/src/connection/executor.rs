@@ -1,4 +1,4 @@
-use std::{ops::ControlFlow, pin::pin};
+use std::{net::SocketAddr, ops::ControlFlow, pin::pin};
 
 use bytes::Bytes;
 use futures_core::{Stream, future::BoxFuture, stream::BoxStream};
@@ -7,39 +7,47 @@ use scylla::{
     deserialize::row::ColumnIterator,
     response::{
         PagingState, PagingStateResponse,
-        query_result::{ColumnSpecs, QueryResult},
+        query_result::{ColumnSpecs, QueryResult, QueryRowsResult},
+    },
+    statement::{
+        Statement,
+        batch::{Batch, BatchStatement},
+        prepared::PreparedStatement,
     },
-    statement::Statement,
 };
 
 use sqlx_core::{
     Either, Error, connection::Connection, executor::Executor, row::Row, sql_str::SqlStr,
     try_stream,
 };
+use tracing::Instrument;
 
 use crate::{
     ScyllaDB, ScyllaDBArguments, ScyllaDBConnection, ScyllaDBError, ScyllaDBQueryResult,
-    ScyllaDBRow, ScyllaDBStatement, ScyllaDBTypeInfo, statement::ScyllaDBStatementMetadata,
+    ScyllaDBRow, ScyllaDBStatement, ScyllaDBTypeInfo,
+    logger::ScyllaDBQueryLogger,
+    lwt::APPLIED_COLUMN,
+    statement::{ScyllaDBDriverStatement, ScyllaDBStatementMetadata},
+    telemetry::{self, QueryMetrics},
 };
 
-const APPLIED_COLUMN: &'static str = "[applied]";
-
 impl ScyllaDBConnection {
-    async fn execute_single_page<'e, 'c: 'e, 'q: 'e>(
-        &'c mut self,
-        sql: &str,
+    async fn execute_single_page(
+        &self,
+        statement: impl Into<Statement>,
         arguments: &Option<ScyllaDBArguments>,
         persistent: bool,
         paging_state: PagingState,
     ) -> Result<(QueryResult, PagingStateResponse), ScyllaDBError> {
+        let statement = statement.into();
         if persistent {
             let (query_result, paging_state_response) = if let Some(arguments) = arguments {
                 self.caching_session
-                    .execute_single_page(sql, arguments, paging_state)
+                    .execute_single_page(statement, arguments, paging_state)
                     .await?
             } else {
                 self.caching_session
-                    .execute_single_page(sql, (), paging_state)
+                    .execute_single_page(statement, (), paging_state)
                     .await?
             };
 
@@ -49,16 +57,145 @@ impl ScyllaDBConnection {
 
             let (query_result, paging_state_response) = if let Some(arguments) = arguments {
                 session
-                    .query_single_page(sql, arguments, paging_state)
+                    .query_single_page(statement, arguments, paging_state)
                     .await?
             } else {
-                session.query_single_page(sql, (), paging_state).await?
+                session
+                    .query_single_page(statement, (), paging_state)
+                    .await?
             };
 
             Ok((query_result, paging_state_response))
         }
     }
 
+    async fn execute_prepared_single_page(
+        &self,
+        prepared_statement: &PreparedStatement,
+        arguments: &Option<ScyllaDBArguments>,
+        paging_state: PagingState,
+    ) -> Result<(QueryResult, PagingStateResponse), ScyllaDBError> {
+        let session = self.caching_session.get_session();
+
+        let (query_result, paging_state_response) = if let Some(arguments) = arguments {
+            session
+                .execute_single_page(prepared_statement, arguments, paging_state)
+                .await?
+        } else {
+            session
+                .execute_single_page(prepared_statement, (), paging_state)
+                .await?
+        };
+
+        Ok((query_result, paging_state_response))
+    }
+
+    async fn execute_batch(
+        &self,
+        batch: &Batch,
+        arguments: Option<ScyllaDBArguments>,
+    ) -> Result<QueryResult, ScyllaDBError> {
+        let mut arguments = arguments.unwrap_or_default();
+
+        // Prepare every statement so that the bound values can be distributed by the number of bind markers.
+        let mut prepared_batch = batch.clone();
+        let mut batch_arguments = Vec::with_capacity(batch.statements.len());
+        for statement in prepared_batch.statements.iter_mut() {
+            let prepared_statement = match statement {
+                BatchStatement::Query(statement) => self
+                    .caching_session
+                    .add_prepared_statement(&*statement)
+                    .await
+                    .map_err(ScyllaDBError::from)?,
+                BatchStatement::PreparedStatement(prepared_statement) => prepared_statement.clone(),
+                _ => return Err(ScyllaDBError::UnsupportedBatchStatement),
+            };
+
+            let parameters = prepared_statement.get_variable_col_specs().len();
+            if arguments.buffer.len() < parameters {
+                return Err(ScyllaDBError::BatchArgumentsCountError {
+                    expect: parameters,
+                    actual: arguments.buffer.len(),
+                });
+            }
+            batch_arguments.push(arguments.split_to(parameters));
+
+            *statement = BatchStatement::PreparedStatement(prepared_statement);
+        }
+
+        if !arguments.buffer.is_empty() {
+            let expect = batch_arguments
+                .iter()
+                .map(|arguments| arguments.buffer.len())
+                .sum();
+            return Err(ScyllaDBError::BatchArgumentsCountError {
+                expect,
+                actual: expect + arguments.buffer.len(),
+            });
+        }
+
+        let query_result = self
+            .caching_session
+            .get_session()
+            .batch(&prepared_batch, &batch_arguments)
+            .await?;
+
+        Ok(query_result)
+    }
+
+    /// Return the prepared statement from the [scylla::client::caching_session::CachingSession] cache, preparing it if needed.
+    pub(crate) async fn prepared_statement(
+        &mut self,
+        sql: &str,
+    ) -> Result<PreparedStatement, ScyllaDBError> {
+        if let Some(prepared_statement) = self.reprepared_statements.get_mut(sql) {
+            return Ok(prepared_statement.clone());
+        }
+
+        let statement = Statement::new(sql).with_page_size(self.page_size);
+        let prepared_statement = self
+            .caching_session
+            .add_prepared_statement(&statement)
+            .await
+            .map_err(ScyllaDBError::from)?;
+
+        #[cfg(feature = "metrics")]
+        self.track_prepared_statement(sql, &prepared_statement);
+
+        Ok(prepared_statement)
+    }
+
+    /// Export whether the statement was returned from the [scylla::client::caching_session::CachingSession] cache.
+    ///
+    /// A cache hit shares the data of the statement returned before, while a statement prepared again gets its own.
+    #[cfg(feature = "metrics")]
+    fn track_prepared_statement(&mut self, sql: &str, prepared_statement: &PreparedStatement) {
+        let cache_hit = self.prepared_statements.get_mut(sql).is_some_and(|cached| {
+            std::ptr::eq(cached.get_statement(), prepared_statement.get_statement())
+        });
+        if !cache_hit {
+            self.prepared_statements
+                .insert(sql, prepared_statement.clone());
+        }
+
+        telemetry::record_prepare_cache(cache_hit);
+    }
+
+    /// Prepare the statement again, bypassing the possibly stale [scylla::client::caching_session::CachingSession] cache.
+    async fn reprepare(&mut self, sql: &str) -> Result<(), ScyllaDBError> {
+        let statement = Statement::new(sql).with_page_size(self.page_size);
+        let prepared_statement = self
+            .caching_session
+            .get_session()
+            .prepare(statement)
+            .await
+            .map_err(ScyllaDBError::from)?;
+
+        self.reprepared_statements.insert(sql, prepared_statement);
+
+        Ok(())
+    }
+
     pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         sql: SqlStr,
@@ -68,59 +205,69 @@ impl ScyllaDBConnection {
         impl Stream<Item = Result<Either<ScyllaDBQueryResult, ScyllaDBRow>, Error>> + 'e,
         Error,
     > {
-        Ok(try_stream! {
-            let statement = self.prepare(sql.clone()).await?;
+        let span = telemetry::query_span(self.caching_session.get_session(), sql.as_str(), None);
+        let stream_span = span.clone();
+        let statement_sql = sql.clone();
+        let redact = self.redact_parameters;
+
+        let stream = try_stream! {
+            let statement = self.prepare(sql.clone()).instrument(stream_span.clone()).await?;
 
             // INSERT, UPDATE, and DELETE queries during transactions are processed in batches.
             let in_batch = self.is_in_transaction() && statement.is_affect_statement;
 
             if !in_batch {
+                self.check_read_in_transaction(statement.sql.as_str())?;
+
+                let mut logger = ScyllaDBQueryLogger::new(sql, arguments.as_slice(), self.log_settings.clone(), self.redact_parameters);
+                let metrics = QueryMetrics::new("query");
                 let mut paging_state = PagingState::start();
+                let mut page_count = 0;
+                let mut rows_returned = 0;
+                let mut metadata_checked = statement.is_affect_statement || !persistent;
 
                 loop {
-                    let (query_result, paging_state_response) = self.execute_single_page(statement.sql.as_str(), &arguments, persistent, paging_state.clone()).await?;
-
-                    if !query_result.is_rows() {
-                        break;
-                    }
-
-                    let rows_result = query_result.into_rows_result().map_err(ScyllaDBError::IntoRowsResultError)?;
-                    let column_specs = rows_result.column_specs();
-                    let metadata = ScyllaDBStatementMetadata::from_column_specs(column_specs)?;
-
-                    let is_lwt = column_specs.is_lwt();
-                    let rows_num = rows_result.rows_num() as u64;
-                    let mut rows_affected = 0;
-
-                    let rows = rows_result.rows::<ColumnIterator<'_,'_>>().map_err(ScyllaDBError::RowsError)?;
-                    for row in rows {
-                        let row = row.map_err(ScyllaDBError::DeserializationError)?;
-
-                        let mut columns: Vec<Option<Bytes>> = Vec::with_capacity(row.columns_remaining());
-                        for column in row {
-                            let column = column.map_err(ScyllaDBError::DeserializationError)?;
-                            let column = match column.slice {
-                                Some(slice) => {
-                                    Some(slice.to_bytes())
-                                },
-                                None => None,
-                            };
-                            columns.push(column)
+                    // Statements prepared again after a schema change are executed without the stale cache.
+                    let reprepared_statement = if persistent {
+                        self.reprepared_statements.get_mut(statement.sql.as_str()).cloned()
+                    } else {
+                        None
+                    };
+                    let (query_result, paging_state_response) = match reprepared_statement {
+                        Some(prepared_statement) => {
+                            self.execute_prepared_single_page(&prepared_statement, &arguments, paging_state.clone()).instrument(stream_span.clone()).await?
                         }
-
-                        let scylladb_row = ScyllaDBRow::new(columns, metadata.clone());
-
-                        if is_lwt {
-                            let applied: bool = scylladb_row.try_get(APPLIED_COLUMN).unwrap_or(false);
-                            if applied {
-                                rows_affected += 1;
-                            }
+                        _ => {
+                            self.execute_single_page(statement.sql.as_str(), &arguments, persistent, paging_state.clone()).instrument(stream_span.clone()).await?
+                        }
+                    };
+                    page_count += 1;
+                    metrics.increment_pages();
+                    telemetry::record_coordinator(&stream_span, &query_result);
+
+                    let mut page = Page::new(query_result)?;
+
+                    // Rows are decoded with the metadata of the response, but the prepared statement is stale after a schema change such as ALTER TABLE.
+                    if !metadata_checked {
+                        metadata_checked = true;
+                        if let Some(metadata) = &page.metadata
+                            && !metadata.has_same_columns(&statement.metadata)
+                        {
+                            self.reprepare(statement.sql.as_str()).instrument(stream_span.clone()).await?;
                         }
+                    }
+
+                    rows_returned += page.query_result.rows_num;
+                    telemetry::record_rows(&stream_span, page_count, rows_returned);
 
-                        r#yield!(Either::Right(scylladb_row))
+                    for row in page.rows()? {
+                        logger.increment_rows_returned();
+                        r#yield!(Either::Right(row?));
                     }
 
-                    r#yield!(Either::Left(ScyllaDBQueryResult { rows_num, rows_affected }));
+                    let query_result = page.into_query_result();
+                    logger.increase_rows_affected(query_result.rows_affected);
+                    r#yield!(Either::Left(query_result));
 
                     match paging_state_response.into_paging_control_flow() {
                         ControlFlow::Break(()) => {
@@ -132,11 +279,237 @@ impl ScyllaDBConnection {
                     }
                 }
             } else {
-                self.append_to_transaction(sql, arguments).await?;
+                self.append_to_transaction(sql, arguments).instrument(stream_span.clone()).await?;
             }
 
             Ok(())
-        })
+        };
+
+        Ok(stream
+            .map_err(move |error| with_statement(error, statement_sql.as_str(), redact))
+            .inspect_err(move |error| telemetry::record_error(&span, error)))
+    }
+
+    pub(crate) async fn run_driver_statement<'e, 'c: 'e>(
+        &'c mut self,
+        sql: SqlStr,
+        driver_statement: Either<Statement, PreparedStatement>,
+        arguments: Option<ScyllaDBArguments>,
+        persistent: bool,
+    ) -> Result<
+        impl Stream<Item = Result<Either<ScyllaDBQueryResult, ScyllaDBRow>, Error>> + 'e,
+        Error,
+    > {
+        let consistency = match &driver_statement {
+            Either::Left(statement) => statement.get_consistency(),
+            Either::Right(prepared_statement) => prepared_statement.get_consistency(),
+        };
+        let span = telemetry::query_span(
+            self.caching_session.get_session(),
+            sql.as_str(),
+            consistency,
+        );
+        let stream_span = span.clone();
+        let statement_sql = sql.clone();
+        let redact = self.redact_parameters;
+
+        let stream = try_stream! {
+            self.check_read_in_transaction(sql.as_str())?;
+
+            let mut logger = ScyllaDBQueryLogger::new(sql, arguments.as_slice(), self.log_settings.clone(), self.redact_parameters);
+            let metrics = QueryMetrics::new("statement");
+            let mut paging_state = PagingState::start();
+            let mut page_count = 0;
+            let mut rows_returned = 0;
+
+            loop {
+                let (query_result, paging_state_response) = match &driver_statement {
+                    Either::Left(statement) => {
+                        self.execute_single_page(statement.clone(), &arguments, persistent, paging_state.clone()).instrument(stream_span.clone()).await?
+                    }
+                    Either::Right(prepared_statement) => {
+                        self.execute_prepared_single_page(prepared_statement, &arguments, paging_state.clone()).instrument(stream_span.clone()).await?
+                    }
+                };
+                page_count += 1;
+                metrics.increment_pages();
+                telemetry::record_coordinator(&stream_span, &query_result);
+
+                let mut page = Page::new(query_result)?;
+
+                rows_returned += page.query_result.rows_num;
+                telemetry::record_rows(&stream_span, page_count, rows_returned);
+
+                for row in page.rows()? {
+                    logger.increment_rows_returned();
+                    r#yield!(Either::Right(row?));
+                }
+
+                let query_result = page.into_query_result();
+                logger.increase_rows_affected(query_result.rows_affected);
+                r#yield!(Either::Left(query_result));
+
+                match paging_state_response.into_paging_control_flow() {
+                    ControlFlow::Break(()) => {
+                        break;
+                    }
+                    ControlFlow::Continue(new_paging_state) => {
+                        paging_state = new_paging_state
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+        Ok(stream
+            .map_err(move |error| with_statement(error, statement_sql.as_str(), redact))
+            .inspect_err(move |error| telemetry::record_error(&span, error)))
+    }
+
+    pub(crate) async fn run_driver_batch<'e, 'c: 'e>(
+        &'c mut self,
+        sql: SqlStr,
+        batch: Batch,
+        arguments: Option<ScyllaDBArguments>,
+    ) -> Result<
+        impl Stream<Item = Result<Either<ScyllaDBQueryResult, ScyllaDBRow>, Error>> + 'e,
+        Error,
+    > {
+        let span = telemetry::query_span(
+            self.caching_session.get_session(),
+            sql.as_str(),
+            batch.get_consistency(),
+        );
+        let stream_span = span.clone();
+        let statement_sql = sql.clone();
+        let redact = self.redact_parameters;
+
+        let stream = try_stream! {
+            self.check_read_in_transaction(sql.as_str())?;
+
+            let mut logger = ScyllaDBQueryLogger::new(sql, arguments.as_slice(), self.log_settings.clone(), self.redact_parameters);
+            let metrics = QueryMetrics::new("batch");
+            let query_result = self.execute_batch(&batch, arguments).instrument(stream_span.clone()).await?;
+            metrics.increment_pages();
+            telemetry::record_batch(batch.statements.len());
+            telemetry::record_coordinator(&stream_span, &query_result);
+
+            let mut page = Page::new(query_result)?;
+            telemetry::record_rows(&stream_span, 1, page.query_result.rows_num);
+
+            for row in page.rows()? {
+                logger.increment_rows_returned();
+                r#yield!(Either::Right(row?));
+            }
+
+            let query_result = page.into_query_result();
+            logger.increase_rows_affected(query_result.rows_affected);
+            r#yield!(Either::Left(query_result));
+
+            Ok(())
+        };
+
+        Ok(stream
+            .map_err(move |error| with_statement(error, statement_sql.as_str(), redact))
+            .inspect_err(move |error| telemetry::record_error(&span, error)))
+    }
+}
+
+/// Attach the statement text to errors returned by ScyllaDB.
+fn with_statement(error: Error, sql: &str, redact: bool) -> Error {
+    match error {
+        Error::Database(database_error) => match database_error.try_downcast::<ScyllaDBError>() {
+            Ok(error) => Error::Database(Box::new((*error).with_statement(sql, redact))),
+            Err(database_error) => Error::Database(database_error),
+        },
+        error => error,
+    }
+}
+
+/// A page of a response, whose rows are decoded one by one while they are streamed.
+struct Page {
+    rows_result: Option<QueryRowsResult>,
+    metadata: Option<ScyllaDBStatementMetadata>,
+    query_result: ScyllaDBQueryResult,
+}
+
+impl Page {
+    fn new(query_result: QueryResult) -> Result<Self, ScyllaDBError> {
+        let tracing_id = query_result.tracing_id();
+        let warnings = query_result.warnings().map(str::to_string).collect();
+        let address = query_result.request_coordinator().node().address;
+        let coordinator = Some(SocketAddr::new(address.ip(), address.port()));
+
+        let mut page = Page {
+            rows_result: None,
+            metadata: None,
+            query_result: ScyllaDBQueryResult {
+                tracing_id,
+                warnings,
+                coordinator,
+                pages: 1,
+                ..Default::default()
+            },
+        };
+
+        if query_result.is_rows() {
+            let rows_result = query_result.into_rows_result()?;
+            let column_specs = rows_result.column_specs();
+            page.metadata = Some(ScyllaDBStatementMetadata::from_column_specs(column_specs)?);
+            page.query_result.is_lwt = column_specs.is_lwt();
+            page.query_result.rows_num = rows_result.rows_num() as u64;
+            page.rows_result = Some(rows_result);
+        }
+
+        Ok(page)
+    }
+
+    /// Decode the rows of the page, counting the applied rows of a light-weight transaction.
+    fn rows(
+        &mut self,
+    ) -> Result<impl Iterator<Item = Result<ScyllaDBRow, ScyllaDBError>> + '_, ScyllaDBError> {
+        let Self {
+            rows_result,
+            metadata,
+            query_result,
+        } = self;
+        let metadata = metadata.clone().unwrap_or_default();
+        let rows = rows_result
+            .as_ref()
+            .map(|rows_result| rows_result.rows::<ColumnIterator<'_, '_>>())
+            .transpose()?;
+
+        Ok(rows.into_iter().flatten().map(move |row| {
+            let row = row?;
+
+            let mut columns: Vec<Option<Bytes>> = Vec::with_capacity(row.columns_remaining());
+            for column in row {
+                let column = column?;
+                let column = column.slice.map(|slice| slice.to_bytes());
+                columns.push(column)
+            }
+
+            let scylladb_row = ScyllaDBRow::new(columns, metadata.clone());
+
+            if query_result.is_lwt {
+                let applied: bool = scylladb_row.try_get(APPLIED_COLUMN).unwrap_or(false);
+                if applied {
+                    query_result.rows_affected += 1;
+                }
+            }
+
+            Ok(scylladb_row)
+        }))
+    }
+
+    fn into_query_result(self) -> ScyllaDBQueryResult {
+        let mut query_result = self.query_result;
+        if query_result.is_lwt {
+            query_result.applied = Some(query_result.rows_affected == query_result.rows_num);
+        }
+
+        query_result
     }
 }
 
@@ -153,16 +526,54 @@ impl<'c> Executor<'c> for &'c mut ScyllaDBConnection {
         'q: 'e,
         E: 'q,
     {
-        let arguments = query.take_arguments().map_err(Error::Encode);
+        let counter_coercion = self.counter_coercion;
+        let arguments = query
+            .take_arguments()
+            .map_err(Error::Encode)
+            .map(|arguments| {
+                arguments.map(|mut arguments| {
+                    arguments.counter_coercion = counter_coercion;
+                    arguments
+                })
+            });
         let persistent = query.persistent();
+        let driver_statement = query
+            .statement()
+            .and_then(|statement| statement.driver_statement.clone());
         let sql = query.sql();
 
         Box::pin(try_stream! {
             let arguments = arguments?;
-            let mut s = pin!(self.run(sql, arguments, persistent).await?);
 
-            while let Some(v) = s.try_next().await? {
-                r#yield!(v);
+            match driver_statement {
+                Some(ScyllaDBDriverStatement::Unprepared(statement)) => {
+                    let mut s = pin!(self.run_driver_statement(sql, Either::Left(statement), arguments, persistent).await?);
+
+                    while let Some(v) = s.try_next().await? {
+                        r#yield!(v);
+                    }
+                }
+                Some(ScyllaDBDriverStatement::Prepared(prepared_statement)) => {
+                    let mut s = pin!(self.run_driver_statement(sql, Either::Right(prepared_statement), arguments, persistent).await?);
+
+                    while let Some(v) = s.try_next().await? {
+                        r#yield!(v);
+                    }
+                }
+                Some(ScyllaDBDriverStatement::Batch(batch)) => {
+                    let mut s = pin!(self.run_driver_batch(sql, batch, arguments).await?);
+
+                    while let Some(v) = s.try_next().await? {
+                        r#yield!(v);
+                    }
+                }
+                None => {
+                    let mut s = pin!(self.run(sql, arguments, persistent).await?);
+
+                    while let Some(v) = s.try_next().await? {
+                        r#yield!(v);
+                    }
+                }
             }
 
             Ok(())
@@ -198,13 +609,20 @@ impl<'c> Executor<'c> for &'c mut ScyllaDBConnection {
     where
         'c: 'e,
     {
+        let span = telemetry::prepare_span(self.caching_session.get_session(), sql.as_str());
+
         Box::pin(async move {
-            let statement = Statement::new(sql.as_str()).with_page_size(self.page_size);
+            #[cfg(feature = "metrics")]
+            let start = std::time::Instant::now();
+
             let prepared_statement = self
-                .caching_session
-                .add_prepared_statement(&statement)
+                .prepared_statement(sql.as_str())
+                .instrument(span.clone())
                 .await
-                .map_err(ScyllaDBError::PrepareError)?;
+                .inspect_err(|error| telemetry::record_error(&span, error))?;
+
+            #[cfg(feature = "metrics")]
+            telemetry::record_prepare(start.elapsed());
 
             let column_specs_guard = prepared_statement.get_current_result_set_col_specs();
             let column_specs = column_specs_guard.get();
@@ -216,6 +634,7 @@ impl<'c> Executor<'c> for &'c mut ScyllaDBConnection {
                 sql,
                 metadata,
                 is_affect_statement,
+                driver_statement: None,
             })
         })
     }
@@ -233,7 +652,7 @@ impl<'c> Executor<'c> for &'c mut ScyllaDBConnection {
                 .caching_session
                 .add_prepared_statement(&statement)
                 .await
-                .map_err(ScyllaDBError::PrepareError)?;
+                .map_err(ScyllaDBError::from)?;
             let column_specs_guard = prepared_statement.get_current_result_set_col_specs();
             let column_specs = column_specs_guard.get();
 
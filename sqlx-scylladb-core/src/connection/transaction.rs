@@ -1,20 +1,67 @@
-use scylla::statement::{Statement, batch::Batch};
+use std::collections::BTreeMap;
 
-use crate::{ScyllaDBArguments, ScyllaDBConnection, ScyllaDBError};
-use sqlx_core::sql_str::SqlStr;
+use scylla::{
+    frame::response::result::ColumnSpec,
+    serialize::{
+        row::{RowSerializationContext, SerializeRow},
+        writers::RowWriter,
+    },
+    statement::batch::{Batch, BatchType},
+};
+
+use crate::{
+    ScyllaDBArguments, ScyllaDBConnection, ScyllaDBError, ScyllaDBTransactionOptions,
+    ScyllaDBTransactionReadPolicy,
+    logger::ScyllaDBQueryLogger,
+    telemetry::{self, QueryMetrics},
+};
+use sqlx_core::sql_str::{AssertSqlSafe, SqlSafeStr, SqlStr};
+use tracing::Instrument;
 
 pub(crate) struct ScyllaDBTransaction {
     statements: Vec<SqlStr>,
     arguments: Vec<ScyllaDBArguments>,
+    /// Serialized size of the arguments of each statement, measured only when a byte limit is set.
+    sizes: Vec<usize>,
+    bytes: usize,
+    options: ScyllaDBTransactionOptions,
+    /// Number of buffered statements when each nested transaction began.
+    savepoints: Vec<usize>,
 }
 
-impl Default for ScyllaDBTransaction {
-    fn default() -> Self {
+impl ScyllaDBTransaction {
+    fn new(options: ScyllaDBTransactionOptions) -> Self {
         Self {
             statements: Default::default(),
             arguments: Default::default(),
+            sizes: Default::default(),
+            bytes: 0,
+            options,
+            savepoints: Default::default(),
         }
     }
+
+    /// Whether a nested `BEGIN BATCH` statement describes the batch of this transaction.
+    fn shares_batch(&self, batch_type: BatchType, timestamp: Option<i64>) -> bool {
+        batch_type == self.options.batch_type
+            && timestamp.is_none_or(|timestamp| self.options.timestamp == Some(timestamp))
+    }
+
+    /// Discard the statements appended since the innermost nested transaction began.
+    ///
+    /// Returns `false` if there is no nested transaction.
+    fn rollback_savepoint(&mut self) -> bool {
+        let Some(savepoint) = self.savepoints.pop() else {
+            return false;
+        };
+
+        self.statements.truncate(savepoint);
+        self.arguments.truncate(savepoint);
+        self.sizes.truncate(savepoint);
+        self.bytes = self.sizes.iter().sum();
+
+        true
+    }
 }
 
 impl ScyllaDBConnection {
@@ -22,43 +69,195 @@ impl ScyllaDBConnection {
         &mut self,
         statement: Option<SqlStr>,
     ) -> Result<(), ScyllaDBError> {
-        if self.transaction.is_none() {
-            self.transaction = Some(ScyllaDBTransaction::default())
+        let options = self.next_transaction_options.take();
+        let begin_batch = match &statement {
+            Some(statement) => parse_begin_statement(statement.as_str())?,
+            None => None,
+        };
+
+        if let Some(transaction) = &mut self.transaction {
+            // A nested transaction shares the batch of the outermost one, so it cannot configure it.
+            let shares_batch = begin_batch.is_none_or(|(batch_type, timestamp)| {
+                transaction.shares_batch(batch_type, timestamp)
+            });
+            if options.is_some() || !shares_batch {
+                return Err(ScyllaDBError::NestedTransactionOptions);
+            }
+            transaction.savepoints.push(transaction.statements.len());
+            if let Some(statement) = statement.filter(|_| begin_batch.is_none()) {
+                self.append_to_transaction(statement, None).await?;
+            }
+
+            return Ok(());
         }
+        self.transaction = Some(ScyllaDBTransaction::new(options.unwrap_or_default()));
 
         if let Some(statement) = statement {
-            self.append_to_transaction(statement, None).await?;
+            // A BEGIN BATCH statement selects the batch type instead of being buffered.
+            match begin_batch {
+                Some((batch_type, timestamp)) => {
+                    if let Some(transaction) = &mut self.transaction {
+                        transaction.options.batch_type = batch_type;
+                        if timestamp.is_some() {
+                            transaction.options.timestamp = timestamp;
+                        }
+                    }
+                }
+                None => self.append_to_transaction(statement, None).await?,
+            }
         }
 
         Ok(())
     }
 
     pub(crate) async fn commit_transaction(&mut self) -> Result<(), ScyllaDBError> {
-        if let Some(transaction) = &self.transaction {
-            let mut batch = Batch::default();
+        // Committing a nested transaction keeps its statements in the outer batch.
+        if self
+            .transaction
+            .as_mut()
+            .is_some_and(|transaction| transaction.savepoints.pop().is_some())
+        {
+            return Ok(());
+        }
+
+        // The transaction ends even if a batch fails, although the batches already sent
+        // when splitting by token have been applied.
+        if let Some(transaction) = self.transaction.take() {
+            let options = &transaction.options;
+
+            let mut sql = match options.batch_type {
+                BatchType::Unlogged => String::from("BEGIN UNLOGGED BATCH "),
+                BatchType::Counter => String::from("BEGIN COUNTER BATCH "),
+                _ => String::from("BEGIN BATCH "),
+            };
+            let mut prepared_statements = Vec::with_capacity(transaction.statements.len());
             for statement in &transaction.statements {
-                let statement = Statement::new(statement.as_str());
-                batch.append_statement(statement);
+                sql.push_str(statement.as_str());
+                sql.push_str("; ");
+
+                // Statements were already prepared when they were buffered, so this normally hits the cache.
+                let prepared_statement = self
+                    .prepared_statement(statement.as_str())
+                    .await
+                    .map_err(|error| {
+                        error.with_statement(statement.as_str(), self.redact_parameters)
+                    })?;
+                prepared_statements.push(prepared_statement);
             }
+            sql.push_str("APPLY BATCH");
 
-            self.caching_session
-                .batch(&batch, &transaction.arguments)
-                .await?;
-        }
+            let groups = if options.can_split()
+                && options.exceeds_limits(transaction.statements.len(), transaction.bytes)
+            {
+                let tokens = prepared_statements
+                    .iter()
+                    .zip(&transaction.arguments)
+                    .map(|(prepared_statement, arguments)| {
+                        // Statements without a computable token are grouped together.
+                        prepared_statement
+                            .calculate_token(arguments)
+                            .ok()
+                            .flatten()
+                            .map(|token| token.value())
+                    })
+                    .collect::<Vec<_>>();
+                split_by_token(&tokens, &transaction.sizes, options)
+            } else {
+                vec![(0..prepared_statements.len()).collect()]
+            };
 
-        self.transaction = None;
+            let span = telemetry::query_span(self.caching_session.get_session(), &sql, None);
+
+            let sql = AssertSqlSafe(sql).into_sql_str();
+            let _logger = ScyllaDBQueryLogger::new(
+                sql.clone(),
+                &transaction.arguments,
+                self.log_settings.clone(),
+                self.redact_parameters,
+            );
+
+            for group in groups {
+                let mut batch = Batch::new(options.batch_type);
+                if let Some(consistency) = options.consistency {
+                    batch.set_consistency(consistency);
+                }
+                if let Some(serial_consistency) = options.serial_consistency {
+                    batch.set_serial_consistency(Some(serial_consistency));
+                }
+                batch.set_timestamp(options.timestamp);
+
+                let mut arguments = Vec::with_capacity(group.len());
+                for index in group {
+                    batch.append_statement(prepared_statements[index].clone());
+                    arguments.push(&transaction.arguments[index]);
+                }
+
+                let metrics = QueryMetrics::new("batch");
+                let query_result = self
+                    .caching_session
+                    .batch(&batch, &arguments)
+                    .instrument(span.clone())
+                    .await
+                    .map_err(|error| {
+                        ScyllaDBError::from(error)
+                            .with_statement(sql.as_str(), self.redact_parameters)
+                    })
+                    .inspect_err(|error| telemetry::record_error(&span, error))?;
+                telemetry::record_coordinator(&span, &query_result);
+                telemetry::record_batch(arguments.len());
+                metrics.increment_pages();
+            }
+            telemetry::record_rows(&span, 1, 0);
+        }
 
         Ok(())
     }
 
     pub(crate) fn rollback_transaction(&mut self) -> Result<(), ScyllaDBError> {
+        if self
+            .transaction
+            .as_mut()
+            .is_some_and(ScyllaDBTransaction::rollback_savepoint)
+        {
+            return Ok(());
+        }
+
         self.transaction = None;
 
         Ok(())
     }
 
+    /// Apply the read policy to a statement sent immediately inside the transaction.
+    ///
+    /// Only `SELECT` statements are reads; other statements such as DDL pass through.
+    pub(crate) fn check_read_in_transaction(&self, sql: &str) -> Result<(), ScyllaDBError> {
+        let Some(transaction) = &self.transaction else {
+            return Ok(());
+        };
+        if !is_select_statement(sql) {
+            return Ok(());
+        }
+
+        match transaction.options.read_policy {
+            ScyllaDBTransactionReadPolicy::PassThrough => {}
+            ScyllaDBTransactionReadPolicy::Reject => {
+                return Err(ScyllaDBError::ReadInTransaction);
+            }
+            ScyllaDBTransactionReadPolicy::Warn => {
+                log::warn!(
+                    target: "sqlx_scylladb::transaction",
+                    "read inside a transaction does not see its buffered writes: {sql}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get_transaction_depth(&self) -> usize {
-        if self.transaction.is_some() { 1 } else { 0 }
+        self.transaction
+            .as_ref()
+            .map_or(0, |transaction| transaction.savepoints.len() + 1)
     }
 
     pub(crate) async fn append_to_transaction<'e, 'c: 'e, 'q: 'e>(
@@ -66,9 +265,38 @@ impl ScyllaDBConnection {
         sql: SqlStr,
         arguments: Option<ScyllaDBArguments>,
     ) -> Result<(), ScyllaDBError> {
+        let Some(options) = self
+            .transaction
+            .as_ref()
+            .map(|transaction| &transaction.options)
+        else {
+            return Err(ScyllaDBError::TransactionNotStarted);
+        };
+        let arguments = arguments.unwrap_or_default();
+
+        let size = if options.max_bytes.is_some() {
+            let prepared_statement = self.prepared_statement(sql.as_str()).await?;
+            serialized_size(
+                prepared_statement.get_variable_col_specs().as_slice(),
+                &arguments,
+            )?
+        } else {
+            0
+        };
+
         if let Some(transaction) = &mut self.transaction {
+            let statements = transaction.statements.len() + 1;
+            let bytes = transaction.bytes + size;
+            if !transaction.options.can_split()
+                && transaction.options.exceeds_limits(statements, bytes)
+            {
+                return Err(ScyllaDBError::BatchTooLarge { statements, bytes });
+            }
+
             transaction.statements.push(sql);
-            transaction.arguments.push(arguments.unwrap_or_default());
+            transaction.arguments.push(arguments);
+            transaction.sizes.push(size);
+            transaction.bytes = bytes;
         } else {
             return Err(ScyllaDBError::TransactionNotStarted);
         }
@@ -76,3 +304,261 @@ impl ScyllaDBConnection {
         Ok(())
     }
 }
+
+/// Whether the statement reads rows.
+fn is_select_statement(sql: &str) -> bool {
+    sql.split_whitespace()
+        .next()
+        .is_some_and(|token| token.eq_ignore_ascii_case("SELECT"))
+}
+
+/// Measure the size of the arguments serialized for the bind markers.
+fn serialized_size(
+    specs: &[ColumnSpec<'_>],
+    arguments: &ScyllaDBArguments,
+) -> Result<usize, ScyllaDBError> {
+    let ctx = RowSerializationContext::from_specs(specs);
+    let mut buf = Vec::new();
+    arguments.serialize(&ctx, &mut RowWriter::new(&mut buf))?;
+
+    Ok(buf.len())
+}
+
+/// Group statement indices by partition token, then chunk each group to fit the limits.
+///
+/// A single statement exceeding the byte limit is still sent in a batch of its own.
+fn split_by_token(
+    tokens: &[Option<i64>],
+    sizes: &[usize],
+    options: &ScyllaDBTransactionOptions,
+) -> Vec<Vec<usize>> {
+    let mut partitions = BTreeMap::<Option<i64>, Vec<usize>>::new();
+    for (index, token) in tokens.iter().enumerate() {
+        partitions.entry(*token).or_default().push(index);
+    }
+
+    let mut groups = Vec::new();
+    for indices in partitions.into_values() {
+        let mut group = Vec::new();
+        let mut bytes = 0;
+        for index in indices {
+            let size = sizes.get(index).copied().unwrap_or_default();
+            if !group.is_empty() && options.exceeds_limits(group.len() + 1, bytes + size) {
+                groups.push(std::mem::take(&mut group));
+                bytes = 0;
+            }
+            group.push(index);
+            bytes += size;
+        }
+        groups.push(group);
+    }
+
+    groups
+}
+
+/// Parse the statement beginning a transaction.
+///
+/// Returns an error for a statement starting with `BEGIN` that is not a valid `BEGIN BATCH` statement.
+fn parse_begin_statement(sql: &str) -> Result<Option<(BatchType, Option<i64>)>, ScyllaDBError> {
+    if let Some(begin_batch) = parse_begin_batch(sql) {
+        return Ok(Some(begin_batch));
+    }
+
+    let is_begin = sql
+        .split_whitespace()
+        .next()
+        .is_some_and(|token| token.eq_ignore_ascii_case("BEGIN"));
+    if is_begin {
+        return Err(ScyllaDBError::InvalidBeginStatement(sql.to_string()));
+    }
+
+    Ok(None)
+}
+
+/// Parse `BEGIN [UNLOGGED | COUNTER] BATCH [USING TIMESTAMP <timestamp>]`.
+fn parse_begin_batch(sql: &str) -> Option<(BatchType, Option<i64>)> {
+    let mut tokens = sql.trim().trim_end_matches(';').split_whitespace();
+
+    if !tokens.next()?.eq_ignore_ascii_case("BEGIN") {
+        return None;
+    }
+
+    let mut token = tokens.next()?;
+    let batch_type = if token.eq_ignore_ascii_case("UNLOGGED") {
+        token = tokens.next()?;
+        BatchType::Unlogged
+    } else if token.eq_ignore_ascii_case("COUNTER") {
+        token = tokens.next()?;
+        BatchType::Counter
+    } else if token.eq_ignore_ascii_case("LOGGED") {
+        token = tokens.next()?;
+        BatchType::Logged
+    } else {
+        BatchType::Logged
+    };
+
+    if !token.eq_ignore_ascii_case("BATCH") {
+        return None;
+    }
+
+    let timestamp = match tokens.next() {
+        None => None,
+        Some(token) if token.eq_ignore_ascii_case("USING") => {
+            if !tokens.next()?.eq_ignore_ascii_case("TIMESTAMP") {
+                return None;
+            }
+            Some(tokens.next()?.parse().ok()?)
+        }
+        Some(_) => return None,
+    };
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    Some((batch_type, timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use scylla::{
+        cluster::metadata::{ColumnType, NativeType},
+        frame::response::result::{ColumnSpec, TableSpec},
+        statement::batch::BatchType,
+    };
+
+    use sqlx_core::{
+        arguments::Arguments,
+        error::BoxDynError,
+        sql_str::{AssertSqlSafe, SqlSafeStr},
+    };
+
+    use super::{
+        ScyllaDBTransaction, is_select_statement, parse_begin_batch, parse_begin_statement,
+        serialized_size, split_by_token,
+    };
+    use crate::{ScyllaDBArguments, ScyllaDBError, ScyllaDBTransactionOptions};
+
+    #[test]
+    fn it_can_parse_begin_batch() {
+        assert_eq!(
+            Some((BatchType::Logged, None)),
+            parse_begin_batch("BEGIN BATCH")
+        );
+        assert_eq!(
+            Some((BatchType::Unlogged, None)),
+            parse_begin_batch("begin unlogged batch;")
+        );
+        assert_eq!(
+            Some((BatchType::Counter, Some(1234))),
+            parse_begin_batch("BEGIN COUNTER BATCH USING TIMESTAMP 1234")
+        );
+        assert_eq!(None, parse_begin_batch("BEGIN BATCH USING TTL 10"));
+        assert_eq!(
+            None,
+            parse_begin_batch("INSERT INTO my_tests(my_id) VALUES(1)")
+        );
+    }
+
+    #[test]
+    fn it_can_check_nested_batch() {
+        let transaction = ScyllaDBTransaction::new(
+            ScyllaDBTransactionOptions::new()
+                .batch_type(BatchType::Unlogged)
+                .timestamp(1234),
+        );
+
+        assert!(transaction.shares_batch(BatchType::Unlogged, None));
+        assert!(transaction.shares_batch(BatchType::Unlogged, Some(1234)));
+        assert!(!transaction.shares_batch(BatchType::Unlogged, Some(5)));
+        assert!(!transaction.shares_batch(BatchType::Counter, None));
+    }
+
+    #[test]
+    fn it_rejects_invalid_begin_statement() {
+        assert!(matches!(
+            parse_begin_statement("BEGIN BATCH USING TTL 10"),
+            Err(ScyllaDBError::InvalidBeginStatement(_))
+        ));
+        assert!(matches!(
+            parse_begin_statement("begin transaction"),
+            Err(ScyllaDBError::InvalidBeginStatement(_))
+        ));
+        assert!(matches!(
+            parse_begin_statement("INSERT INTO my_tests(my_id) VALUES(1)"),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn it_can_detect_select_statement() {
+        assert!(is_select_statement("SELECT * FROM my_tests"));
+        assert!(is_select_statement("  select my_id FROM my_tests"));
+        assert!(!is_select_statement(
+            "CREATE TABLE my_selects(my_id BIGINT PRIMARY KEY)"
+        ));
+        assert!(!is_select_statement("TRUNCATE my_tests"));
+    }
+
+    #[test]
+    fn it_can_measure_serialized_size() -> Result<(), BoxDynError> {
+        let table_spec = TableSpec::borrowed("my_keyspace", "my_tests");
+        let specs = [
+            ColumnSpec::borrowed(
+                "my_id",
+                ColumnType::Native(NativeType::BigInt),
+                table_spec.clone(),
+            ),
+            ColumnSpec::borrowed("my_name", ColumnType::Native(NativeType::Text), table_spec),
+        ];
+
+        let mut arguments = ScyllaDBArguments::default();
+        arguments.add(1i64)?;
+        arguments.add("Alice")?;
+
+        // Each value is preceded by its length of 4 bytes.
+        assert_eq!((4 + 8) + (4 + 5), serialized_size(&specs, &arguments)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_split_by_token() {
+        let options = ScyllaDBTransactionOptions::new()
+            .batch_type(BatchType::Unlogged)
+            .max_statements(2)
+            .max_bytes(100)
+            .split_by_token();
+
+        let tokens = [Some(1), Some(2), Some(1), Some(1), None, Some(2)];
+        let sizes = [10, 10, 10, 10, 10, 95];
+        assert_eq!(
+            vec![vec![4], vec![0, 2], vec![3], vec![1], vec![5]],
+            split_by_token(&tokens, &sizes, &options)
+        );
+    }
+
+    #[test]
+    fn it_can_rollback_savepoint() {
+        let mut transaction = ScyllaDBTransaction::new(ScyllaDBTransactionOptions::new());
+        let append = |transaction: &mut ScyllaDBTransaction, size| {
+            transaction
+                .statements
+                .push(AssertSqlSafe("INSERT INTO my_tests(my_id) VALUES(1)").into_sql_str());
+            transaction.arguments.push(ScyllaDBArguments::default());
+            transaction.sizes.push(size);
+            transaction.bytes += size;
+        };
+
+        append(&mut transaction, 10);
+        transaction.savepoints.push(transaction.statements.len());
+        append(&mut transaction, 20);
+        append(&mut transaction, 30);
+
+        assert!(transaction.rollback_savepoint());
+        assert_eq!(1, transaction.statements.len());
+        assert_eq!(1, transaction.arguments.len());
+        assert_eq!(10, transaction.bytes);
+        assert!(!transaction.rollback_savepoint());
+    }
+}
@@ -1,12 +1,14 @@
-use std::error::Error as StdError;
+use std::{borrow::Cow, error::Error as StdError};
 
+use bytes::Bytes;
 use scylla::{
     cluster::metadata::ColumnType,
     errors::{
-        DeserializationError, ExecutionError, IntoRowsResultError, NewSessionError,
-        PagerExecutionError, PrepareError, RowsError, SerializationError, TypeCheckError,
-        UseKeyspaceError,
+        DbError, DeserializationError, ExecutionError, IntoRowsResultError, NewSessionError,
+        NextPageError, PagerExecutionError, PrepareError, RequestAttemptError, RequestError,
+        RowsError, SerializationError, TracingError, TypeCheckError, UseKeyspaceError, WriteType,
     },
+    statement::Consistency,
 };
 use sqlx_core::error::{DatabaseError, ErrorKind};
 use sqlx_core::ext::ustr::UStr;
@@ -20,13 +22,13 @@ pub enum ScyllaDBError {
     #[error("Configuration error. {0}")]
     ConfigurationError(String),
     /// Error occurred while creating the session.
-    NewSessionError(#[from] NewSessionError),
+    NewSessionError(Box<NewSessionError>),
     /// There is an error in the specified keyspace.
     UseKeyspaceError(#[from] UseKeyspaceError),
     /// Error occurred while preparing the statement.
-    PrepareError(#[from] PrepareError),
+    PrepareError(PrepareError),
     /// Error occurred while converting to rows result.
-    IntoRowsResultError(#[from] IntoRowsResultError),
+    IntoRowsResultError(Box<IntoRowsResultError>),
     /// Error occurred while retrieving the row.
     RowsError(#[from] RowsError),
     /// Error occurred while type checking.
@@ -35,13 +37,137 @@ pub enum ScyllaDBError {
     SerializationError(#[from] SerializationError),
     /// Error occurred while deserialization.
     DeserializationError(#[from] DeserializationError),
+    /// Error occurred while encoding a bound value.
+    #[error(
+        "Failed to encode the parameter at index {index} ({column_name}: {column_type:?}). {source}"
+    )]
+    ArgumentEncodeError {
+        /// index of the bound value.
+        index: usize,
+        /// name of the bind marker.
+        column_name: String,
+        /// type of the bind marker.
+        column_type: ColumnType<'static>,
+        /// cause of the error.
+        source: SerializationError,
+    },
+    /// Error occurred while decoding a column.
+    #[error("Failed to decode the column {column_name} ({column_type:?}). {source}")]
+    ColumnDecodeError {
+        /// column name.
+        column_name: UStr,
+        /// column type.
+        column_type: ColumnType<'static>,
+        /// cause of the error.
+        source: DeserializationError,
+    },
+    /// Error occurred while executing a statement.
+    #[error("{source} Statement: {statement}")]
+    StatementError {
+        /// statement text, with literals redacted when `redact_parameters` is set.
+        statement: String,
+        /// cause of the error.
+        source: Box<ScyllaDBError>,
+    },
     /// Error occurred while execution.
-    ExecutionError(#[from] ExecutionError),
+    ExecutionError(Box<ExecutionError>),
+    /// Not enough replicas are alive to achieve the requested consistency level.
+    #[error("Unavailable. {message}")]
+    Unavailable {
+        /// requested consistency level.
+        consistency: Consistency,
+        /// number of replicas required.
+        required: i32,
+        /// number of replicas alive.
+        alive: i32,
+        /// message from the server.
+        message: String,
+    },
+    /// The replicas did not respond to a read request in time.
+    #[error("Read timeout. {message}")]
+    ReadTimeout {
+        /// requested consistency level.
+        consistency: Consistency,
+        /// number of replicas that responded.
+        received: i32,
+        /// number of replicas required.
+        required: i32,
+        /// whether the replica asked for data responded.
+        data_present: bool,
+        /// message from the server.
+        message: String,
+    },
+    /// The replicas did not respond to a write request in time.
+    #[error("Write timeout. {message}")]
+    WriteTimeout {
+        /// requested consistency level.
+        consistency: Consistency,
+        /// number of replicas that acknowledged the write.
+        received: i32,
+        /// number of replicas required.
+        required: i32,
+        /// type of the write.
+        write_type: WriteType,
+        /// message from the server.
+        message: String,
+    },
+    /// The coordinator is overloaded.
+    #[error("Overloaded. {0}")]
+    Overloaded(String),
+    /// The statement has a syntax error.
+    #[error("Syntax error. {0}")]
+    SyntaxError(String),
+    /// The statement is syntactically correct but invalid.
+    #[error("Invalid. {0}")]
+    Invalid(String),
+    /// The user is not allowed to perform the statement.
+    #[error("Unauthorized. {0}")]
+    Unauthorized(String),
+    /// The keyspace or table to create already exists.
+    #[error("Already exists. {message}")]
+    AlreadyExists {
+        /// keyspace name.
+        keyspace: String,
+        /// table name, empty when the keyspace already exists.
+        table: String,
+        /// message from the server.
+        message: String,
+    },
+    /// The prepared statement is not known by the server.
+    #[error("Unprepared. {message}")]
+    Unprepared {
+        /// ID of the prepared statement.
+        statement_id: Bytes,
+        /// message from the server.
+        message: String,
+    },
     /// Error occurred while pagination.
-    PagerExecutionError(#[from] PagerExecutionError),
+    PagerExecutionError(Box<PagerExecutionError>),
+    /// Error occurred while retrieving the tracing information.
+    TracingError(Box<TracingError>),
     /// Transaction is not started.
     #[error("Transaction is not started.")]
     TransactionNotStarted,
+    /// The statement beginning a transaction is not a valid `BEGIN BATCH` statement.
+    #[error("Invalid statement to begin a transaction. {0}")]
+    InvalidBeginStatement(String),
+    /// Options or a different `BEGIN BATCH` statement were given for a nested transaction,
+    /// which shares the batch of the outermost one.
+    #[error("Transaction options cannot be set for a nested transaction.")]
+    NestedTransactionOptions,
+    /// A read was executed inside a transaction whose read policy rejects reads.
+    #[error(
+        "Reads inside a transaction do not see its buffered writes, and the read policy rejects them."
+    )]
+    ReadInTransaction,
+    /// The transaction exceeds the batch size limits.
+    #[error("Transaction exceeds the batch size limits. statements: {statements}, bytes: {bytes}")]
+    BatchTooLarge {
+        /// number of statements including the rejected one.
+        statements: usize,
+        /// size of the serialized arguments including the rejected statement.
+        bytes: usize,
+    },
     /// Attempted to retrieve data exceeding the number of columns.
     #[error("Column index out of bounds. the len is {len}, but the index is {index}")]
     ColumnIndexOutOfBounds {
@@ -73,12 +199,368 @@ pub enum ScyllaDBError {
     /// Failed to acquire exclusive lock.
     #[error("Exclusive lock error.")]
     ExclusiveLockError,
+    /// The number of bound values does not match the bind markers of the batch.
+    #[error("Batch arguments count is mismatched. expect: {expect}, actual: {actual}")]
+    BatchArgumentsCountError {
+        /// expected number of arguments.
+        expect: usize,
+        /// actual number of arguments.
+        actual: usize,
+    },
+    /// The batch contains a kind of statement that is not supported.
+    #[error("Batch statement is not supported.")]
+    UnsupportedBatchStatement,
+    /// The number of vector elements does not match the dimensions of the column.
+    #[error("Vector dimensions are mismatched. expect: {expect}, actual: {actual}")]
+    VectorDimensionError {
+        /// dimensions of the column.
+        expect: usize,
+        /// number of elements.
+        actual: usize,
+    },
+    /// The statement given to an ANN query already has an `ORDER BY` or a `LIMIT` clause.
+    #[error("Invalid statement for an ANN query. {0}")]
+    InvalidAnnStatement(String),
+    /// Failed to convert between a duration value and a duration column.
+    #[error("Duration conversion error. {0}")]
+    DurationConversionError(String),
+    /// Failed to convert a decimal value without losing precision.
+    #[error("Decimal conversion error. {0}")]
+    DecimalConversionError(String),
+}
+
+impl ScyllaDBError {
+    /// Return the error without the statement context.
+    pub fn without_context(&self) -> &ScyllaDBError {
+        match self {
+            ScyllaDBError::StatementError { source, .. } => source.without_context(),
+            _ => self,
+        }
+    }
+
+    /// Attach the statement text to the error.
+    pub(crate) fn with_statement(self, sql: &str, redact: bool) -> ScyllaDBError {
+        if let ScyllaDBError::StatementError { .. } = self {
+            return self;
+        }
+
+        let statement = if redact {
+            redact_literals(sql)
+        } else {
+            sql.to_string()
+        };
+
+        ScyllaDBError::StatementError {
+            statement,
+            source: Box::new(self),
+        }
+    }
+
+    /// Return the error code of the native protocol if the error was returned by the server.
+    pub fn code(&self) -> Option<i32> {
+        match self.without_context() {
+            ScyllaDBError::Unavailable { .. } => Some(0x1000),
+            ScyllaDBError::Overloaded(_) => Some(0x1001),
+            ScyllaDBError::WriteTimeout { .. } => Some(0x1100),
+            ScyllaDBError::ReadTimeout { .. } => Some(0x1200),
+            ScyllaDBError::SyntaxError(_) => Some(0x2000),
+            ScyllaDBError::Unauthorized(_) => Some(0x2100),
+            ScyllaDBError::Invalid(_) => Some(0x2200),
+            ScyllaDBError::AlreadyExists { .. } => Some(0x2400),
+            ScyllaDBError::Unprepared { .. } => Some(0x2500),
+            ScyllaDBError::ExecutionError(error) => match error.as_ref() {
+                ExecutionError::LastAttemptError(RequestAttemptError::DbError(db_error, _)) => {
+                    db_error_code(db_error)
+                }
+                _ => None,
+            },
+            ScyllaDBError::PrepareError(PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::DbError(db_error, _),
+            }) => db_error_code(db_error),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed when retried.
+    ///
+    /// Write timeouts are retryable only for simple writes and batches, since counter and
+    /// compare-and-set writes may already have been applied.
+    pub fn is_retryable(&self) -> bool {
+        match self.without_context() {
+            ScyllaDBError::Unavailable { .. }
+            | ScyllaDBError::ReadTimeout { .. }
+            | ScyllaDBError::Overloaded(_)
+            | ScyllaDBError::Unprepared { .. } => true,
+            ScyllaDBError::WriteTimeout { write_type, .. } => matches!(
+                write_type,
+                WriteType::Simple
+                    | WriteType::Batch
+                    | WriteType::UnloggedBatch
+                    | WriteType::BatchLog
+            ),
+            ScyllaDBError::ExecutionError(error) => matches!(
+                error.as_ref(),
+                ExecutionError::RequestTimeout(_)
+                    | ExecutionError::ConnectionPoolError(_)
+                    | ExecutionError::LastAttemptError(RequestAttemptError::BrokenConnectionError(
+                        _
+                    ))
+            ),
+            ScyllaDBError::PrepareError(error) => matches!(
+                error,
+                PrepareError::ConnectionPoolError(_)
+                    | PrepareError::AllAttemptsFailed {
+                        first_attempt: RequestAttemptError::BrokenConnectionError(_)
+                    }
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl From<ExecutionError> for ScyllaDBError {
+    fn from(error: ExecutionError) -> Self {
+        match error {
+            ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+                ref db_error,
+                ref message,
+            )) => from_db_error(db_error, message)
+                .unwrap_or(ScyllaDBError::ExecutionError(Box::new(error))),
+            ExecutionError::PrepareError(error) => error.into(),
+            error => ScyllaDBError::ExecutionError(Box::new(error)),
+        }
+    }
+}
+
+impl From<PrepareError> for ScyllaDBError {
+    fn from(error: PrepareError) -> Self {
+        match error {
+            PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::DbError(ref db_error, ref message),
+            } => from_db_error(db_error, message).unwrap_or(ScyllaDBError::PrepareError(error)),
+            error => ScyllaDBError::PrepareError(error),
+        }
+    }
+}
+
+impl From<PagerExecutionError> for ScyllaDBError {
+    fn from(error: PagerExecutionError) -> Self {
+        match error {
+            PagerExecutionError::PrepareError(error) => error.into(),
+            PagerExecutionError::NextPageError(NextPageError::RequestFailure(
+                RequestError::LastAttemptError(RequestAttemptError::DbError(
+                    ref db_error,
+                    ref message,
+                )),
+            )) => from_db_error(db_error, message)
+                .unwrap_or(ScyllaDBError::PagerExecutionError(Box::new(error))),
+            error => ScyllaDBError::PagerExecutionError(Box::new(error)),
+        }
+    }
+}
+
+impl From<NewSessionError> for ScyllaDBError {
+    fn from(error: NewSessionError) -> Self {
+        ScyllaDBError::NewSessionError(Box::new(error))
+    }
+}
+
+impl From<IntoRowsResultError> for ScyllaDBError {
+    fn from(error: IntoRowsResultError) -> Self {
+        ScyllaDBError::IntoRowsResultError(Box::new(error))
+    }
+}
+
+impl From<TracingError> for ScyllaDBError {
+    fn from(error: TracingError) -> Self {
+        ScyllaDBError::TracingError(Box::new(error))
+    }
+}
+
+/// Map an error returned by the server to the variant with its details.
+fn from_db_error(db_error: &DbError, message: &str) -> Option<ScyllaDBError> {
+    let message = message.to_string();
+    let error = match db_error.clone() {
+        DbError::Unavailable {
+            consistency,
+            required,
+            alive,
+        } => ScyllaDBError::Unavailable {
+            consistency,
+            required,
+            alive,
+            message,
+        },
+        DbError::ReadTimeout {
+            consistency,
+            received,
+            required,
+            data_present,
+        } => ScyllaDBError::ReadTimeout {
+            consistency,
+            received,
+            required,
+            data_present,
+            message,
+        },
+        DbError::WriteTimeout {
+            consistency,
+            received,
+            required,
+            write_type,
+        } => ScyllaDBError::WriteTimeout {
+            consistency,
+            received,
+            required,
+            write_type,
+            message,
+        },
+        DbError::Overloaded => ScyllaDBError::Overloaded(message),
+        DbError::SyntaxError => ScyllaDBError::SyntaxError(message),
+        DbError::Invalid => ScyllaDBError::Invalid(message),
+        DbError::Unauthorized => ScyllaDBError::Unauthorized(message),
+        DbError::AlreadyExists { keyspace, table } => ScyllaDBError::AlreadyExists {
+            keyspace,
+            table,
+            message,
+        },
+        DbError::Unprepared { statement_id } => ScyllaDBError::Unprepared {
+            statement_id,
+            message,
+        },
+        _ => return None,
+    };
+
+    Some(error)
+}
+
+/// Replace string, numeric, blob and UUID literals in the statement, which may contain sensitive values.
+fn redact_literals(sql: &str) -> String {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut redacted = String::with_capacity(sql.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\'' => {
+                i += 1;
+                while i < chars.len() {
+                    if chars[i] == '\'' {
+                        // A doubled quote is an escaped quote inside the literal.
+                        if chars.get(i + 1) != Some(&'\'') {
+                            break;
+                        }
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+                redacted.push_str("'<redacted>'");
+            }
+            '"' => {
+                // A quoted identifier is kept even if it looks like a literal.
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|c| *c == '"')
+                    .map_or(chars.len(), |position| i + position + 2);
+                redacted.extend(&chars[i..end]);
+                i = end;
+            }
+            c if is_identifier_char(c) => {
+                let end = if is_uuid_at(&chars, i) {
+                    i + 36
+                } else if c.is_ascii_digit() {
+                    number_end(&chars, i)
+                } else {
+                    let end = chars[i..]
+                        .iter()
+                        .position(|c| !is_identifier_char(*c))
+                        .map_or(chars.len(), |position| i + position);
+                    redacted.extend(&chars[i..end]);
+                    i = end;
+                    continue;
+                };
+                redacted.push_str("<redacted>");
+                i = end;
+            }
+            c => {
+                redacted.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    redacted
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether a UUID literal such as `123e4567-e89b-12d3-a456-426614174000` starts at the index.
+fn is_uuid_at(chars: &[char], start: usize) -> bool {
+    let Some(uuid) = chars.get(start..start + 36) else {
+        return false;
+    };
+    let is_uuid = uuid.iter().enumerate().all(|(index, c)| match index {
+        8 | 13 | 18 | 23 => *c == '-',
+        _ => c.is_ascii_hexdigit(),
+    });
+
+    is_uuid
+        && !chars
+            .get(start + 36)
+            .is_some_and(|c| is_identifier_char(*c))
+}
+
+/// Return the end of a numeric or blob literal starting at the index.
+fn number_end(chars: &[char], start: usize) -> usize {
+    let is_blob = chars
+        .get(start + 1)
+        .is_some_and(|c| c.eq_ignore_ascii_case(&'x'));
+    let mut end = start;
+    while let Some(c) = chars.get(end) {
+        let is_exponent_sign =
+            !is_blob && (*c == '-' || *c == '+') && chars[end - 1].eq_ignore_ascii_case(&'e');
+        if !(is_identifier_char(*c) || *c == '.' || is_exponent_sign) {
+            break;
+        }
+        end += 1;
+    }
+
+    end
+}
+
+fn db_error_code(db_error: &DbError) -> Option<i32> {
+    let code = match db_error {
+        DbError::ServerError => 0x0000,
+        DbError::ProtocolError => 0x000A,
+        DbError::AuthenticationError => 0x0100,
+        DbError::Unavailable { .. } => 0x1000,
+        DbError::Overloaded => 0x1001,
+        DbError::IsBootstrapping => 0x1002,
+        DbError::TruncateError => 0x1003,
+        DbError::WriteTimeout { .. } => 0x1100,
+        DbError::ReadTimeout { .. } => 0x1200,
+        DbError::ReadFailure { .. } => 0x1300,
+        DbError::FunctionFailure { .. } => 0x1400,
+        DbError::WriteFailure { .. } => 0x1500,
+        DbError::SyntaxError => 0x2000,
+        DbError::Unauthorized => 0x2100,
+        DbError::Invalid => 0x2200,
+        DbError::ConfigError => 0x2300,
+        DbError::AlreadyExists { .. } => 0x2400,
+        DbError::Unprepared { .. } => 0x2500,
+        DbError::Other(code) => *code,
+        _ => return None,
+    };
+
+    Some(code)
 }
 
 impl DatabaseError for ScyllaDBError {
     fn message(&self) -> &str {
         match self {
-            ScyllaDBError::ConfigurationError(message) => &message,
+            ScyllaDBError::ConfigurationError(message) => message,
             ScyllaDBError::NewSessionError(_) => "New session error.",
             ScyllaDBError::UseKeyspaceError(_) => "Use keyspace error.",
             ScyllaDBError::PrepareError(_) => "Prepare error.",
@@ -87,9 +569,26 @@ impl DatabaseError for ScyllaDBError {
             ScyllaDBError::TypeCheckError(_) => "Type check error.",
             ScyllaDBError::SerializationError(_) => "Serialization error.",
             ScyllaDBError::DeserializationError(_) => "Deserialization error.",
+            ScyllaDBError::ArgumentEncodeError { .. } => "Argument encode error.",
+            ScyllaDBError::ColumnDecodeError { .. } => "Column decode error.",
+            ScyllaDBError::StatementError { source, .. } => source.message(),
             ScyllaDBError::ExecutionError(_) => "Execution error.",
+            ScyllaDBError::Unavailable { message, .. }
+            | ScyllaDBError::ReadTimeout { message, .. }
+            | ScyllaDBError::WriteTimeout { message, .. }
+            | ScyllaDBError::AlreadyExists { message, .. }
+            | ScyllaDBError::Unprepared { message, .. } => message,
+            ScyllaDBError::Overloaded(message)
+            | ScyllaDBError::SyntaxError(message)
+            | ScyllaDBError::Invalid(message)
+            | ScyllaDBError::Unauthorized(message) => message,
             ScyllaDBError::PagerExecutionError(_) => "Pager execution error.",
+            ScyllaDBError::TracingError(_) => "Tracing error.",
             ScyllaDBError::TransactionNotStarted => "Transaction is not started.",
+            ScyllaDBError::InvalidBeginStatement(_) => "Invalid begin statement.",
+            ScyllaDBError::NestedTransactionOptions => "Nested transaction options.",
+            ScyllaDBError::ReadInTransaction => "Read in transaction.",
+            ScyllaDBError::BatchTooLarge { .. } => "Batch too large.",
             ScyllaDBError::ColumnIndexOutOfBounds { index: _, len: _ } => {
                 "Column index out of bounds."
             }
@@ -102,9 +601,22 @@ impl DatabaseError for ScyllaDBError {
             ScyllaDBError::ColumnTypeNotSupportedError(_) => "Column type not supported.",
             ScyllaDBError::NullValueError(_) => "Null value error",
             ScyllaDBError::ExclusiveLockError => "Exclusive lock error.",
+            ScyllaDBError::BatchArgumentsCountError {
+                expect: _,
+                actual: _,
+            } => "Batch arguments count error.",
+            ScyllaDBError::UnsupportedBatchStatement => "Unsupported batch statement.",
+            ScyllaDBError::VectorDimensionError { .. } => "Vector dimension error.",
+            ScyllaDBError::InvalidAnnStatement(_) => "Invalid ANN statement.",
+            ScyllaDBError::DurationConversionError(_) => "Duration conversion error.",
+            ScyllaDBError::DecimalConversionError(_) => "Decimal conversion error.",
         }
     }
 
+    fn code(&self) -> Option<Cow<'_, str>> {
+        ScyllaDBError::code(self).map(|code| Cow::Owned(format!("{code:04X}")))
+    }
+
     fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
         self
     }
@@ -118,6 +630,141 @@ impl DatabaseError for ScyllaDBError {
     }
 
     fn kind(&self) -> ErrorKind {
+        // An existing keyspace or table is not a unique constraint, which CQL does not have.
         ErrorKind::Other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use scylla::errors::{DbError, ExecutionError, PrepareError, RequestAttemptError, WriteType};
+    use scylla::statement::Consistency;
+    use sqlx_core::error::{DatabaseError, ErrorKind};
+
+    use super::{ScyllaDBError, redact_literals};
+
+    fn db_error(db_error: DbError, message: &str) -> ScyllaDBError {
+        ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+            db_error,
+            message.to_string(),
+        ))
+        .into()
+    }
+
+    #[test]
+    fn it_can_map_server_errors() {
+        let error = db_error(DbError::Overloaded, "overloaded");
+        assert!(matches!(&error, ScyllaDBError::Overloaded(message) if message == "overloaded"));
+        assert_eq!(Some(0x1001), error.code());
+        assert!(error.is_retryable());
+        assert_eq!("overloaded", error.message());
+
+        let error = db_error(DbError::SyntaxError, "line 1:0 no viable alternative");
+        assert!(matches!(error, ScyllaDBError::SyntaxError(_)));
+        assert_eq!(Some(0x2000), error.code());
+        assert!(!error.is_retryable());
+        assert_eq!(Some("2000"), DatabaseError::code(&error).as_deref());
+
+        let error = db_error(
+            DbError::AlreadyExists {
+                keyspace: String::from("my_keyspace"),
+                table: String::from("my_tests"),
+            },
+            "already exists",
+        );
+        assert!(matches!(error, ScyllaDBError::AlreadyExists { .. }));
+        assert_eq!(ErrorKind::Other, error.kind());
+    }
+
+    #[test]
+    fn it_can_attach_statement() {
+        let error = db_error(DbError::Overloaded, "overloaded")
+            .with_statement("SELECT * FROM my_tests WHERE my_name = 'Alice'", true);
+        assert!(matches!(
+            &error,
+            ScyllaDBError::StatementError { statement, .. }
+                if statement == "SELECT * FROM my_tests WHERE my_name = '<redacted>'"
+        ));
+        assert!(matches!(
+            error.without_context(),
+            ScyllaDBError::Overloaded(_)
+        ));
+        assert!(error.is_retryable());
+        assert_eq!(Some(0x1001), error.code());
+        assert_eq!("overloaded", error.message());
+    }
+
+    #[test]
+    fn it_can_redact_literals() {
+        assert_eq!(
+            "INSERT INTO t(a, b) VALUES('<redacted>', ?)",
+            redact_literals("INSERT INTO t(a, b) VALUES('it''s', ?)")
+        );
+        assert_eq!("SELECT a FROM t", redact_literals("SELECT a FROM t"));
+        assert_eq!(
+            "UPDATE t1 SET a = <redacted>, b = -<redacted>, c = <redacted> WHERE id = ?",
+            redact_literals("UPDATE t1 SET a = 42, b = -1.5e-3, c = 0xCAFE WHERE id = ?")
+        );
+        assert_eq!(
+            "SELECT a FROM t WHERE id IN (<redacted>, <redacted>)",
+            redact_literals(
+                "SELECT a FROM t WHERE id IN (f47ac10b-58cc-4372-a567-0e02b2c3d479, 123e4567-e89b-12d3-a456-426614174000)"
+            )
+        );
+        assert_eq!(
+            "SELECT \"1st\" FROM t WHERE a = :a1 LIMIT <redacted>",
+            redact_literals("SELECT \"1st\" FROM t WHERE a = :a1 LIMIT 10")
+        );
+    }
+
+    #[test]
+    fn it_can_map_prepare_errors() {
+        let error: ScyllaDBError = PrepareError::AllAttemptsFailed {
+            first_attempt: RequestAttemptError::DbError(
+                DbError::SyntaxError,
+                String::from("line 1:0 no viable alternative"),
+            ),
+        }
+        .into();
+        assert!(matches!(error, ScyllaDBError::SyntaxError(_)));
+        assert_eq!(Some(0x2000), error.code());
+
+        let error: ScyllaDBError = PrepareError::AllAttemptsFailed {
+            first_attempt: RequestAttemptError::DbError(
+                DbError::IsBootstrapping,
+                String::from("bootstrapping"),
+            ),
+        }
+        .into();
+        assert!(matches!(error, ScyllaDBError::PrepareError(_)));
+        assert_eq!(Some(0x1002), error.code());
+    }
+
+    #[test]
+    fn it_retries_write_timeouts_of_idempotent_writes() {
+        let write_timeout = |write_type| {
+            db_error(
+                DbError::WriteTimeout {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    write_type,
+                },
+                "write timeout",
+            )
+        };
+
+        assert!(write_timeout(WriteType::Simple).is_retryable());
+        assert!(write_timeout(WriteType::BatchLog).is_retryable());
+        assert!(!write_timeout(WriteType::Counter).is_retryable());
+        assert!(!write_timeout(WriteType::Cas).is_retryable());
+    }
+
+    #[test]
+    fn it_keeps_other_server_errors() {
+        let error = db_error(DbError::IsBootstrapping, "bootstrapping");
+        assert!(matches!(error, ScyllaDBError::ExecutionError(_)));
+        assert_eq!(Some(0x1002), error.code());
+        assert!(!error.is_retryable());
+    }
+}
@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
 #![doc = include_str!("lib.md")]
 
+mod ann;
 #[cfg(feature = "any")]
 pub mod any;
 mod arguments;
@@ -8,12 +9,16 @@ mod column;
 mod connection;
 mod database;
 mod error;
+mod logger;
+mod lwt;
 #[cfg(feature = "migrate")]
 mod migrate;
 mod options;
+mod query;
 mod query_result;
 mod row;
 mod statement;
+mod telemetry;
 #[cfg(feature = "migrate")]
 mod testing;
 mod transaction;
@@ -21,12 +26,17 @@ mod type_info;
 mod types;
 mod value;
 
+pub use ann::query_ann_as;
 pub use arguments::{ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBArguments};
 pub use column::ScyllaDBColumn;
 pub use connection::ScyllaDBConnection;
+#[cfg(feature = "metrics")]
+pub use connection::ScyllaDBDriverMetrics;
 pub use database::ScyllaDB;
 pub use error::ScyllaDBError;
+pub use lwt::ScyllaDBLwtResult;
 pub use options::{ScyllaDBCompression, ScyllaDBConnectOptions, ScyllaDBReplicationStrategy};
+pub use query::{ScyllaDBDriverQuery, query_statement};
 pub use query_result::ScyllaDBQueryResult;
 pub use row::ScyllaDBRow;
 use sqlx_core::{
@@ -36,10 +46,14 @@ use sqlx_core::{
     pool::{Pool, PoolOptions},
     transaction::Transaction,
 };
-pub use statement::ScyllaDBStatement;
-pub use transaction::ScyllaDBTransactionManager;
+pub use statement::{ScyllaDBDriverStatement, ScyllaDBStatement};
+pub use transaction::{
+    ScyllaDBTransactionManager, ScyllaDBTransactionOptions, ScyllaDBTransactionReadPolicy,
+};
 pub use type_info::{ScyllaDBTypeInfo, register_any_type};
 pub use types::array::ScyllaDBHasArrayType;
+pub use types::counter::CounterDelta;
+pub use types::duration::{format_iso8601_duration, parse_iso8601_duration};
 pub use types::user_defined_type::UserDefinedType;
 pub use value::{ScyllaDBValue, ScyllaDBValueRef};
 
@@ -0,0 +1,128 @@
+use std::time::Instant;
+
+use log::LevelFilter;
+use sqlx_core::{
+    connection::LogSettings,
+    logger::{QueryLogger, parse_query_summary},
+    sql_str::SqlStr,
+};
+use tracing::Level;
+
+use crate::ScyllaDBArguments;
+
+const TARGET: &str = "sqlx::query";
+
+// `tracing` requires a constant level at each callsite.
+macro_rules! with_tracing_level {
+    ($level:expr, $macro:ident!($($args:tt)*)) => {
+        match $level {
+            Level::ERROR => tracing::$macro!(target: TARGET, Level::ERROR, $($args)*),
+            Level::WARN => tracing::$macro!(target: TARGET, Level::WARN, $($args)*),
+            Level::INFO => tracing::$macro!(target: TARGET, Level::INFO, $($args)*),
+            Level::DEBUG => tracing::$macro!(target: TARGET, Level::DEBUG, $($args)*),
+            Level::TRACE => tracing::$macro!(target: TARGET, Level::TRACE, $($args)*),
+        }
+    };
+}
+
+/// Logs the executed statement with [QueryLogger] when dropped,
+/// preceded by an event with the bound values, which [QueryLogger] does not log.
+pub(crate) struct ScyllaDBQueryLogger {
+    logger: QueryLogger,
+    parameters: Option<String>,
+    start: Instant,
+    settings: LogSettings,
+}
+
+impl ScyllaDBQueryLogger {
+    pub(crate) fn new(
+        sql: SqlStr,
+        arguments: &[ScyllaDBArguments],
+        settings: LogSettings,
+        redact_parameters: bool,
+    ) -> Self {
+        // Formatting the bound values is skipped unless the statement may be logged.
+        let parameters = if is_enabled(settings.statements_level)
+            || is_enabled(settings.slow_statements_level)
+        {
+            let parameters: Vec<String> = arguments
+                .iter()
+                .map(|arguments| arguments.to_log_string(redact_parameters))
+                .collect();
+            Some(parameters.join(", "))
+        } else {
+            None
+        };
+
+        Self {
+            logger: QueryLogger::new(sql, settings.clone()),
+            parameters,
+            start: Instant::now(),
+            settings,
+        }
+    }
+
+    pub(crate) fn increment_rows_returned(&mut self) {
+        self.logger.increment_rows_returned();
+    }
+
+    pub(crate) fn increase_rows_affected(&mut self, n: u64) {
+        self.logger.increase_rows_affected(n);
+    }
+
+    fn log_parameters(&self) {
+        let Some(parameters) = &self.parameters else {
+            return;
+        };
+
+        // Same level as the statement logged by QueryLogger.
+        let level = if self.start.elapsed() >= self.settings.slow_statements_duration {
+            self.settings.slow_statements_level
+        } else {
+            self.settings.statements_level
+        };
+
+        let Some(tracing_level) = to_tracing_level(level) else {
+            return;
+        };
+
+        if !is_enabled(level) {
+            return;
+        }
+
+        let summary = parse_query_summary(self.logger.sql().as_str());
+        with_tracing_level!(
+            tracing_level,
+            event!(summary, db.parameters = parameters, "statement parameters")
+        );
+    }
+}
+
+impl Drop for ScyllaDBQueryLogger {
+    fn drop(&mut self) {
+        // The wrapped QueryLogger logs the statement when dropped after this.
+        self.log_parameters();
+    }
+}
+
+fn to_tracing_level(level: LevelFilter) -> Option<Level> {
+    match level {
+        LevelFilter::Error => Some(Level::ERROR),
+        LevelFilter::Warn => Some(Level::WARN),
+        LevelFilter::Info => Some(Level::INFO),
+        LevelFilter::Debug => Some(Level::DEBUG),
+        LevelFilter::Trace => Some(Level::TRACE),
+        LevelFilter::Off => None,
+    }
+}
+
+fn is_enabled(level: LevelFilter) -> bool {
+    match (to_tracing_level(level), level.to_level()) {
+        // The enabled level could be set from either tracing world or log world.
+        (Some(tracing_level), Some(log_level)) => {
+            log::log_enabled!(target: TARGET, log_level)
+                || with_tracing_level!(tracing_level, enabled!())
+        }
+        _ => false,
+    }
+}
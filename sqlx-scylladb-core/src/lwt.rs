@@ -0,0 +1,63 @@
+use sqlx_core::{Error, from_row::FromRow, row::Row};
+
+use crate::ScyllaDBRow;
+
+pub(crate) const APPLIED_COLUMN: &str = "[applied]";
+
+/// The result of a lightweight transaction such as `IF NOT EXISTS`, `IF EXISTS` or `IF` conditions.
+///
+/// Decode a row returned by a conditional statement, or by a batch containing conditional statements, with [FromRow].
+///
+/// ```rust,ignore
+/// let result: ScyllaDBLwtResult<User> =
+///     sqlx::query_as("INSERT INTO users(id, name) VALUES(?, ?) IF NOT EXISTS")
+///         .bind(1i64)
+///         .bind("Alice")
+///         .fetch_one(&pool)
+///         .await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScyllaDBLwtResult<T> {
+    /// The condition was met and the statement was applied.
+    Applied,
+    /// The condition was not met.
+    ///
+    /// Contains the current row, or `None` if the server returned no existing values, as with a failed `IF EXISTS`.
+    NotApplied(Option<T>),
+}
+
+impl<T> ScyllaDBLwtResult<T> {
+    /// Whether the statement was applied.
+    pub fn is_applied(&self) -> bool {
+        matches!(self, Self::Applied)
+    }
+
+    /// Return the current row if the statement was not applied.
+    pub fn into_current_row(self) -> Option<T> {
+        match self {
+            Self::Applied => None,
+            Self::NotApplied(current_row) => current_row,
+        }
+    }
+}
+
+impl<'r, T> FromRow<'r, ScyllaDBRow> for ScyllaDBLwtResult<T>
+where
+    T: FromRow<'r, ScyllaDBRow>,
+{
+    fn from_row(row: &'r ScyllaDBRow) -> Result<Self, Error> {
+        let applied: bool = row.try_get(APPLIED_COLUMN)?;
+        if applied {
+            return Ok(Self::Applied);
+        }
+
+        // Only the [applied] column is returned when there is no existing row.
+        if row.len() <= 1 {
+            return Ok(Self::NotApplied(None));
+        }
+
+        let current_row = T::from_row(row)?;
+
+        Ok(Self::NotApplied(Some(current_row)))
+    }
+}
@@ -32,6 +32,8 @@ pub struct ScyllaDBConnectOptions {
     pub(crate) tls_key: Option<String>,
     pub(crate) tcp_keepalive: Option<Duration>,
     pub(crate) page_size: i32,
+    pub(crate) redact_parameters: bool,
+    pub(crate) counter_coercion: bool,
 }
 
 impl ScyllaDBConnectOptions {
@@ -109,6 +111,12 @@ impl ScyllaDBConnectOptions {
                 "tls_key" => {
                     options = options.tls_key(&value);
                 }
+                "redact_parameters" => {
+                    options = options.redact_parameters();
+                }
+                "counter_coercion" => {
+                    options = options.counter_coercion();
+                }
                 _ => eprintln!("Not supported options. {key}"),
             }
         }
@@ -138,6 +146,8 @@ impl ScyllaDBConnectOptions {
             page_size: DEFAULT_PAGE_SIZE,
             statement_cache_capacity: DEFAULT_STATEMENT_CACHE_CAPACITY,
             log_settings: Default::default(),
+            redact_parameters: false,
+            counter_coercion: false,
         }
     }
 
@@ -238,6 +248,20 @@ impl ScyllaDBConnectOptions {
         self.page_size = page_size;
         self
     }
+
+    /// Redact all bound values in statement logs. Values of the [secrecy_08] crate are always redacted.
+    pub fn redact_parameters(mut self) -> Self {
+        self.redact_parameters = true;
+        self
+    }
+
+    /// Encode integers bound to `counter` columns as counter deltas.
+    ///
+    /// By default, only [crate::CounterDelta] can be bound to a `counter` column.
+    pub fn counter_coercion(mut self) -> Self {
+        self.counter_coercion = true;
+        self
+    }
 }
 
 impl ScyllaDBConnectOptions {
@@ -314,6 +338,14 @@ impl ConnectOptions for ScyllaDBConnectOptions {
             url.query_pairs_mut().append_pair("tls_key", &tls_key);
         }
 
+        if self.redact_parameters {
+            url.query_pairs_mut().append_key_only("redact_parameters");
+        }
+
+        if self.counter_coercion {
+            url.query_pairs_mut().append_key_only("counter_coercion");
+        }
+
         url
     }
 
@@ -806,4 +838,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn it_can_set_redact_parameters() -> anyhow::Result<()> {
+        let options = ScyllaDBConnectOptions::new();
+
+        assert!(!options.redact_parameters);
+
+        let options = options.redact_parameters();
+
+        assert!(options.redact_parameters);
+
+        let options: ScyllaDBConnectOptions =
+            "scylladb://localhost/my_keyspace?redact_parameters".parse()?;
+
+        assert!(options.redact_parameters);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_set_counter_coercion() -> anyhow::Result<()> {
+        let options = ScyllaDBConnectOptions::new();
+
+        assert!(!options.counter_coercion);
+
+        let options = options.counter_coercion();
+
+        assert!(options.counter_coercion);
+
+        let options: ScyllaDBConnectOptions =
+            "scylladb://localhost/my_keyspace?counter_coercion".parse()?;
+
+        assert!(options.counter_coercion);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,104 @@
+use sqlx_core::{
+    arguments::Arguments,
+    encode::Encode,
+    error::BoxDynError,
+    executor::Execute,
+    sql_str::{AssertSqlSafe, SqlSafeStr, SqlStr},
+    types::Type,
+};
+
+use crate::{
+    ScyllaDB, ScyllaDBArguments, ScyllaDBStatement,
+    statement::{ScyllaDBDriverStatement, ScyllaDBStatementMetadata},
+};
+
+/// A query that executes a statement built with scylla-rust-driver.
+///
+/// Statement options such as consistency, timeouts and retry policies set on the driver statement are respected.
+/// For batches, the bound values are distributed to each statement in the order of their bind markers.
+pub struct ScyllaDBDriverQuery {
+    statement: ScyllaDBStatement,
+    arguments: Result<ScyllaDBArguments, BoxDynError>,
+    persistent: bool,
+}
+
+/// Create a query that executes a [scylla::statement::Statement], [scylla::statement::prepared::PreparedStatement] or [scylla::statement::batch::Batch].
+pub fn query_statement(statement: impl Into<ScyllaDBDriverStatement>) -> ScyllaDBDriverQuery {
+    let driver_statement = statement.into();
+
+    let metadata = match &driver_statement {
+        ScyllaDBDriverStatement::Prepared(prepared_statement) => {
+            let column_specs_guard = prepared_statement.get_current_result_set_col_specs();
+            let column_specs = column_specs_guard.get();
+            ScyllaDBStatementMetadata::from_column_specs(column_specs).ok()
+        }
+        _ => None,
+    };
+
+    let statement = ScyllaDBStatement {
+        sql: AssertSqlSafe(driver_statement.to_sql()).into_sql_str(),
+        metadata: metadata.unwrap_or_default(),
+        is_affect_statement: false,
+        driver_statement: Some(driver_statement),
+    };
+
+    ScyllaDBDriverQuery {
+        statement,
+        arguments: Ok(ScyllaDBArguments::default()),
+        persistent: true,
+    }
+}
+
+impl ScyllaDBDriverQuery {
+    /// Bind a value for use with this query.
+    pub fn bind<'t, T: Encode<'t, ScyllaDB> + Type<ScyllaDB>>(mut self, value: T) -> Self {
+        if let Ok(arguments) = &mut self.arguments
+            && let Err(error) = arguments.add(value)
+        {
+            self.arguments = Err(error);
+        }
+
+        self
+    }
+
+    /// If `true`, the request is traced by the server.
+    ///
+    /// The tracing ID is returned in [crate::ScyllaDBQueryResult::tracing_id],
+    /// and the trace can be retrieved by [crate::ScyllaDBConnection::tracing_info].
+    ///
+    /// Queries created by `sqlx::query` or `sqlx::query_as` cannot be traced.
+    /// To trace a SQL string, pass it to [query_statement] as a [scylla::statement::Statement].
+    pub fn tracing(mut self, value: bool) -> Self {
+        if let Some(driver_statement) = &mut self.statement.driver_statement {
+            driver_statement.set_tracing(value);
+        }
+        self
+    }
+
+    /// If `false`, an unprepared statement is sent without being prepared and cached.
+    ///
+    /// The default is `true`.
+    pub fn persistent(mut self, value: bool) -> Self {
+        self.persistent = value;
+        self
+    }
+}
+
+impl<'q> Execute<'q, ScyllaDB> for ScyllaDBDriverQuery {
+    fn sql(self) -> SqlStr {
+        self.statement.sql
+    }
+
+    fn statement(&self) -> Option<&ScyllaDBStatement> {
+        Some(&self.statement)
+    }
+
+    fn take_arguments(&mut self) -> Result<Option<ScyllaDBArguments>, BoxDynError> {
+        let arguments = std::mem::replace(&mut self.arguments, Ok(ScyllaDBArguments::default()));
+        arguments.map(Some)
+    }
+
+    fn persistent(&self) -> bool {
+        self.persistent
+    }
+}
@@ -1,10 +1,29 @@
+use std::net::SocketAddr;
+
+use uuid::Uuid;
+
 /// Query execution result.
+///
+/// Custom payloads are not supported in either direction,
+/// because scylla-rust-driver 1.9 neither exposes the payload of a response nor sends one with a request.
 #[derive(Debug, Default)]
 pub struct ScyllaDBQueryResult {
     /// Number of retrieved items. Or the number of items in the light-weight transaction.
     pub rows_num: u64,
     /// Only valid when using a light-weight transaction.
     pub rows_affected: u64,
+    /// The tracing ID of the first page, when tracing is enabled for the query.
+    pub tracing_id: Option<Uuid>,
+    /// Warnings returned by the server, such as large batch or tombstone warnings.
+    pub warnings: Vec<String>,
+    /// The address of the node that coordinated the first page.
+    pub coordinator: Option<SocketAddr>,
+    /// Number of fetched pages.
+    pub pages: u64,
+    /// Whether the query was a light-weight transaction.
+    pub is_lwt: bool,
+    /// Whether all rows of the light-weight transaction were applied. `None` for other queries.
+    pub applied: Option<bool>,
 }
 
 impl Extend<ScyllaDBQueryResult> for ScyllaDBQueryResult {
@@ -12,6 +31,70 @@ impl Extend<ScyllaDBQueryResult> for ScyllaDBQueryResult {
         for query_result in query_results {
             self.rows_num += query_result.rows_num;
             self.rows_affected += query_result.rows_affected;
+            self.tracing_id = self.tracing_id.or(query_result.tracing_id);
+            self.warnings.extend(query_result.warnings);
+            self.coordinator = self.coordinator.or(query_result.coordinator);
+            self.pages += query_result.pages;
+            self.is_lwt |= query_result.is_lwt;
+            self.applied = match (self.applied, query_result.applied) {
+                (Some(applied), Some(other)) => Some(applied && other),
+                (applied, other) => applied.or(other),
+            };
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ScyllaDBQueryResult;
+
+    #[test]
+    fn it_can_extend_query_results() {
+        let mut query_result = ScyllaDBQueryResult::default();
+        query_result.extend([
+            ScyllaDBQueryResult {
+                rows_num: 2,
+                rows_affected: 2,
+                warnings: vec![String::from("first")],
+                coordinator: Some("127.0.0.1:9042".parse().unwrap()),
+                pages: 1,
+                is_lwt: true,
+                applied: Some(true),
+                ..Default::default()
+            },
+            ScyllaDBQueryResult {
+                rows_num: 1,
+                rows_affected: 0,
+                warnings: vec![String::from("second")],
+                coordinator: Some("127.0.0.2:9042".parse().unwrap()),
+                pages: 1,
+                is_lwt: true,
+                applied: Some(false),
+                ..Default::default()
+            },
+        ]);
+
+        assert_eq!(3, query_result.rows_num);
+        assert_eq!(2, query_result.rows_affected);
+        assert_eq!(vec!["first", "second"], query_result.warnings);
+        assert_eq!(
+            Some("127.0.0.1:9042".parse().unwrap()),
+            query_result.coordinator
+        );
+        assert_eq!(2, query_result.pages);
+        assert!(query_result.is_lwt);
+        assert_eq!(Some(false), query_result.applied);
+    }
+
+    #[test]
+    fn it_has_no_applied_without_lwt() {
+        let mut query_result = ScyllaDBQueryResult::default();
+        query_result.extend([ScyllaDBQueryResult {
+            pages: 1,
+            ..Default::default()
+        }]);
+
+        assert!(!query_result.is_lwt);
+        assert_eq!(None, query_result.applied);
+    }
+}
@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
-use scylla::response::query_result::ColumnSpecs;
+use scylla::{
+    response::query_result::ColumnSpecs,
+    statement::{
+        Statement as DriverStatement,
+        batch::{Batch, BatchStatement},
+        prepared::PreparedStatement,
+    },
+};
 use sqlx_core::{
     Error, HashMap, column::ColumnIndex, ext::ustr::UStr, impl_statement_query, sql_str::SqlStr,
     statement::Statement,
@@ -14,6 +21,7 @@ pub struct ScyllaDBStatement {
     pub(crate) sql: SqlStr,
     pub(crate) metadata: ScyllaDBStatementMetadata,
     pub(crate) is_affect_statement: bool,
+    pub(crate) driver_statement: Option<ScyllaDBDriverStatement>,
 }
 
 impl Statement for ScyllaDBStatement {
@@ -49,6 +57,67 @@ impl ColumnIndex<ScyllaDBStatement> for &'_ str {
     }
 }
 
+/// A statement built with scylla-rust-driver.
+#[derive(Clone)]
+pub enum ScyllaDBDriverStatement {
+    /// Unprepared statement.
+    Unprepared(DriverStatement),
+    /// Prepared statement.
+    Prepared(PreparedStatement),
+    /// Batch statement.
+    Batch(Batch),
+}
+
+impl ScyllaDBDriverStatement {
+    pub(crate) fn set_tracing(&mut self, tracing: bool) {
+        match self {
+            Self::Unprepared(statement) => statement.set_tracing(tracing),
+            Self::Prepared(statement) => statement.set_tracing(tracing),
+            Self::Batch(batch) => batch.set_tracing(tracing),
+        }
+    }
+
+    pub(crate) fn to_sql(&self) -> String {
+        match self {
+            Self::Unprepared(statement) => statement.contents.clone(),
+            Self::Prepared(statement) => statement.get_statement().to_string(),
+            Self::Batch(batch) => {
+                let mut sql = String::from("BEGIN BATCH ");
+                for statement in &batch.statements {
+                    match statement {
+                        BatchStatement::Query(statement) => sql.push_str(&statement.contents),
+                        BatchStatement::PreparedStatement(statement) => {
+                            sql.push_str(statement.get_statement())
+                        }
+                        _ => sql.push_str("<unsupported statement>"),
+                    }
+                    sql.push_str("; ");
+                }
+                sql.push_str("APPLY BATCH");
+                sql
+            }
+        }
+    }
+}
+
+impl From<DriverStatement> for ScyllaDBDriverStatement {
+    fn from(statement: DriverStatement) -> Self {
+        Self::Unprepared(statement)
+    }
+}
+
+impl From<PreparedStatement> for ScyllaDBDriverStatement {
+    fn from(statement: PreparedStatement) -> Self {
+        Self::Prepared(statement)
+    }
+}
+
+impl From<Batch> for ScyllaDBDriverStatement {
+    fn from(batch: Batch) -> Self {
+        Self::Batch(batch)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub(crate) struct ScyllaDBStatementMetadata {
     pub(crate) columns: Arc<Vec<ScyllaDBColumn>>,
@@ -86,4 +155,16 @@ impl ScyllaDBStatementMetadata {
 
         Ok(metadata)
     }
+
+    /// Whether both have the same column names and types.
+    pub(crate) fn has_same_columns(&self, other: &ScyllaDBStatementMetadata) -> bool {
+        self.columns.len() == other.columns.len()
+            && self
+                .columns
+                .iter()
+                .zip(other.columns.iter())
+                .all(|(column, other)| {
+                    column.name == other.name && column.column_type == other.column_type
+                })
+    }
 }
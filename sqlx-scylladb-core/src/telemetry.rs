@@ -0,0 +1,385 @@
+use scylla::{
+    client::session::Session, response::query_result::QueryResult, statement::Consistency,
+};
+use tracing::Span;
+
+#[cfg(feature = "metrics")]
+use std::time::{Duration, Instant};
+
+/// Create a span following the OpenTelemetry database semantic conventions.
+#[cfg(feature = "otel")]
+pub(crate) fn query_span(session: &Session, sql: &str, consistency: Option<Consistency>) -> Span {
+    let operation = operation_name(sql);
+    let keyspace = session.get_keyspace();
+    let consistency = consistency.unwrap_or_else(|| {
+        session
+            .get_default_execution_profile_handle()
+            .to_profile()
+            .get_consistency()
+    });
+
+    tracing::info_span!(
+        target: "sqlx_scylladb::query",
+        "scylladb.query",
+        otel.name = operation,
+        otel.kind = "client",
+        otel.status_code = tracing::field::Empty,
+        db.system.name = "scylladb",
+        db.namespace = keyspace.as_deref().map(|keyspace| keyspace.as_str()),
+        db.operation.name = operation,
+        db.query.text = sql,
+        db.cassandra.consistency_level = %consistency,
+        db.cassandra.coordinator.id = tracing::field::Empty,
+        db.scylladb.page_count = tracing::field::Empty,
+        db.response.returned_rows = tracing::field::Empty,
+        server.address = tracing::field::Empty,
+        server.port = tracing::field::Empty,
+        error.type = tracing::field::Empty,
+        exception.message = tracing::field::Empty,
+    )
+}
+
+/// Returns a disabled span, since the `otel` feature is not enabled.
+#[cfg(not(feature = "otel"))]
+pub(crate) fn query_span(
+    _session: &Session,
+    _sql: &str,
+    _consistency: Option<Consistency>,
+) -> Span {
+    Span::none()
+}
+
+/// Create a span for preparing a statement.
+#[cfg(feature = "otel")]
+pub(crate) fn prepare_span(session: &Session, sql: &str) -> Span {
+    let keyspace = session.get_keyspace();
+
+    tracing::info_span!(
+        target: "sqlx_scylladb::query",
+        "scylladb.prepare",
+        otel.name = "PREPARE",
+        otel.kind = "client",
+        otel.status_code = tracing::field::Empty,
+        db.system.name = "scylladb",
+        db.namespace = keyspace.as_deref().map(|keyspace| keyspace.as_str()),
+        db.operation.name = "PREPARE",
+        db.query.text = sql,
+        error.type = tracing::field::Empty,
+        exception.message = tracing::field::Empty,
+    )
+}
+
+/// Returns a disabled span, since the `otel` feature is not enabled.
+#[cfg(not(feature = "otel"))]
+pub(crate) fn prepare_span(_session: &Session, _sql: &str) -> Span {
+    Span::none()
+}
+
+/// Record the coordinator node of the response.
+#[cfg(feature = "otel")]
+pub(crate) fn record_coordinator(span: &Span, query_result: &QueryResult) {
+    if !span.is_disabled() {
+        let node = query_result.request_coordinator().node();
+        span.record("db.cassandra.coordinator.id", node.host_id.to_string());
+        span.record("server.address", node.address.ip().to_string());
+        span.record("server.port", node.address.port());
+    }
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) fn record_coordinator(_span: &Span, _query_result: &QueryResult) {}
+
+/// Record the number of fetched pages and returned rows.
+#[cfg(feature = "otel")]
+pub(crate) fn record_rows(span: &Span, page_count: u64, rows: u64) {
+    span.record("db.scylladb.page_count", page_count);
+    span.record("db.response.returned_rows", rows);
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) fn record_rows(_span: &Span, _page_count: u64, _rows: u64) {}
+
+/// Record the failure of the request.
+#[cfg(any(feature = "otel", feature = "metrics"))]
+pub(crate) fn record_error(span: &Span, error: &(dyn std::error::Error + 'static)) {
+    let error_type = match error.downcast_ref::<sqlx_core::Error>() {
+        Some(sqlx_core::Error::Database(_)) | None => "database",
+        Some(sqlx_core::Error::Encode(_)) => "encode",
+        Some(sqlx_core::Error::Decode(_)) => "decode",
+        Some(_) => "other",
+    };
+
+    #[cfg(feature = "otel")]
+    {
+        span.record("otel.status_code", "ERROR");
+        span.record("error.type", error_type);
+        span.record("exception.message", error.to_string());
+    }
+
+    #[cfg(not(feature = "otel"))]
+    let _ = span;
+
+    #[cfg(feature = "metrics")]
+    metrics::counter!("sqlx_scylladb_errors_total", "type" => error_type).increment(1);
+}
+
+#[cfg(not(any(feature = "otel", feature = "metrics")))]
+pub(crate) fn record_error(_span: &Span, _error: &(dyn std::error::Error + 'static)) {}
+
+/// Measures a request and exports it through the `metrics` facade when dropped.
+///
+/// The in-flight gauge is global, since a connection runs a single request at a time.
+///
+/// Does nothing unless the `metrics` feature is enabled.
+pub(crate) struct QueryMetrics {
+    #[cfg(feature = "metrics")]
+    kind: &'static str,
+    #[cfg(feature = "metrics")]
+    start: Instant,
+}
+
+impl QueryMetrics {
+    /// `kind` is one of `query`, `statement` or `batch`.
+    #[cfg(feature = "metrics")]
+    pub(crate) fn new(kind: &'static str) -> Self {
+        metrics::gauge!("sqlx_scylladb_queries_in_flight", "kind" => kind).increment(1);
+
+        Self {
+            kind,
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    pub(crate) fn new(_kind: &'static str) -> Self {
+        Self {}
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn increment_pages(&self) {
+        metrics::counter!("sqlx_scylladb_pages_total", "kind" => self.kind).increment(1);
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    pub(crate) fn increment_pages(&self) {}
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for QueryMetrics {
+    fn drop(&mut self) {
+        let kind = self.kind;
+        metrics::gauge!("sqlx_scylladb_queries_in_flight", "kind" => kind).decrement(1);
+        metrics::counter!("sqlx_scylladb_queries_total", "kind" => kind).increment(1);
+        metrics::histogram!("sqlx_scylladb_query_duration_seconds", "kind" => kind)
+            .record(self.start.elapsed());
+    }
+}
+
+/// Export the number of statements in an executed batch.
+#[cfg(feature = "metrics")]
+pub(crate) fn record_batch(statements: usize) {
+    metrics::counter!("sqlx_scylladb_batches_total").increment(1);
+    metrics::histogram!("sqlx_scylladb_batch_statements").record(statements as f64);
+}
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn record_batch(_statements: usize) {}
+
+/// Export a prepare.
+#[cfg(feature = "metrics")]
+pub(crate) fn record_prepare(elapsed: Duration) {
+    metrics::counter!("sqlx_scylladb_prepares_total").increment(1);
+    metrics::histogram!("sqlx_scylladb_prepare_duration_seconds").record(elapsed);
+}
+
+/// Export whether a prepared statement was found in the statement cache.
+#[cfg(feature = "metrics")]
+pub(crate) fn record_prepare_cache(cache_hit: bool) {
+    if cache_hit {
+        metrics::counter!("sqlx_scylladb_prepare_cache_hits_total").increment(1);
+    } else {
+        metrics::counter!("sqlx_scylladb_prepare_cache_misses_total").increment(1);
+    }
+}
+
+#[cfg(feature = "otel")]
+fn operation_name(sql: &str) -> &'static str {
+    let keyword = sql.split_whitespace().next().unwrap_or_default();
+
+    const OPERATIONS: [&str; 12] = [
+        "SELECT", "INSERT", "UPDATE", "DELETE", "BEGIN", "CREATE", "ALTER", "DROP", "TRUNCATE",
+        "USE", "GRANT", "REVOKE",
+    ];
+
+    for operation in OPERATIONS {
+        if keyword.eq_ignore_ascii_case(operation) {
+            return if operation == "BEGIN" {
+                "BATCH"
+            } else {
+                operation
+            };
+        }
+    }
+
+    "OTHER"
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "otel")]
+    mod otel {
+        use crate::telemetry::operation_name;
+
+        #[test]
+        fn it_can_get_operation_name() {
+            assert_eq!("SELECT", operation_name("select * from my_tests"));
+            assert_eq!(
+                "INSERT",
+                operation_name("  INSERT INTO my_tests(my_id) VALUES(?)")
+            );
+            assert_eq!(
+                "BATCH",
+                operation_name("BEGIN BATCH INSERT INTO t(a) VALUES(1); APPLY BATCH")
+            );
+            assert_eq!("OTHER", operation_name("LIST ROLES"));
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    mod metrics {
+        use std::{
+            collections::HashMap,
+            sync::{
+                Arc, Mutex,
+                atomic::{AtomicU64, Ordering},
+            },
+            time::Duration,
+        };
+
+        use metrics::{
+            Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+        };
+
+        use crate::telemetry::{QueryMetrics, record_batch, record_prepare, record_prepare_cache};
+
+        /// Records counters and gauges by name and labels.
+        #[derive(Default)]
+        struct DebuggingRecorder {
+            values: Mutex<HashMap<String, Arc<AtomicU64>>>,
+        }
+
+        impl DebuggingRecorder {
+            fn value(&self, key: &Key) -> Arc<AtomicU64> {
+                let labels = key
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect::<Vec<_>>();
+                let name = format!("{}{{{}}}", key.name(), labels.join(","));
+
+                let mut values = self.values.lock().unwrap();
+                values.entry(name).or_default().clone()
+            }
+
+            fn counter(&self, name: &str) -> u64 {
+                let values = self.values.lock().unwrap();
+                values
+                    .get(name)
+                    .map_or(0, |value| value.load(Ordering::Relaxed))
+            }
+
+            fn gauge(&self, name: &str) -> f64 {
+                let values = self.values.lock().unwrap();
+                values
+                    .get(name)
+                    .map_or(0.0, |value| f64::from_bits(value.load(Ordering::Relaxed)))
+            }
+        }
+
+        impl Recorder for DebuggingRecorder {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                Counter::from_arc(self.value(key))
+            }
+
+            fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+                Gauge::from_arc(self.value(key))
+            }
+
+            fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+                Histogram::noop()
+            }
+        }
+
+        #[test]
+        fn it_can_export_query_metrics() {
+            let recorder = DebuggingRecorder::default();
+
+            metrics::with_local_recorder(&recorder, || {
+                let metrics = QueryMetrics::new("query");
+                assert_eq!(
+                    1.0,
+                    recorder.gauge("sqlx_scylladb_queries_in_flight{kind=query}")
+                );
+
+                metrics.increment_pages();
+                metrics.increment_pages();
+                drop(metrics);
+            });
+
+            assert_eq!(
+                0.0,
+                recorder.gauge("sqlx_scylladb_queries_in_flight{kind=query}")
+            );
+            assert_eq!(
+                1,
+                recorder.counter("sqlx_scylladb_queries_total{kind=query}")
+            );
+            assert_eq!(2, recorder.counter("sqlx_scylladb_pages_total{kind=query}"));
+        }
+
+        #[test]
+        fn it_can_export_batch_metrics() {
+            let recorder = DebuggingRecorder::default();
+
+            metrics::with_local_recorder(&recorder, || {
+                let metrics = QueryMetrics::new("batch");
+                metrics.increment_pages();
+                record_batch(3);
+            });
+
+            assert_eq!(
+                1,
+                recorder.counter("sqlx_scylladb_queries_total{kind=batch}")
+            );
+            assert_eq!(1, recorder.counter("sqlx_scylladb_batches_total{}"));
+        }
+
+        #[test]
+        fn it_can_export_prepare_metrics() {
+            let recorder = DebuggingRecorder::default();
+
+            metrics::with_local_recorder(&recorder, || {
+                record_prepare(Duration::from_millis(1));
+                record_prepare_cache(false);
+                record_prepare(Duration::from_millis(1));
+                record_prepare_cache(true);
+                record_prepare_cache(true);
+            });
+
+            assert_eq!(2, recorder.counter("sqlx_scylladb_prepares_total{}"));
+            assert_eq!(
+                1,
+                recorder.counter("sqlx_scylladb_prepare_cache_misses_total{}")
+            );
+            assert_eq!(
+                2,
+                recorder.counter("sqlx_scylladb_prepare_cache_hits_total{}")
+            );
+        }
+    }
+}
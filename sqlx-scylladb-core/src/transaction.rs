@@ -1,7 +1,121 @@
+use scylla::statement::{Consistency, SerialConsistency, batch::BatchType};
 use sqlx_core::{error::Error, sql_str::SqlStr, transaction::TransactionManager};
 
 use crate::{ScyllaDB, ScyllaDBConnection};
 
+/// How statements that are not buffered, such as SELECT, are handled inside a transaction.
+///
+/// They are sent immediately, so they do not see the writes buffered by the transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScyllaDBTransactionReadPolicy {
+    /// Send the statement immediately.
+    #[default]
+    PassThrough,
+    /// Fail with [ScyllaDBError::ReadInTransaction](crate::ScyllaDBError::ReadInTransaction).
+    Reject,
+    /// Send the statement immediately and log a warning.
+    Warn,
+}
+
+/// Options of the batch statement used to commit a transaction.
+///
+/// Also selected by beginning the transaction with `BEGIN UNLOGGED BATCH` or `BEGIN COUNTER BATCH`.
+#[derive(Debug, Clone)]
+pub struct ScyllaDBTransactionOptions {
+    pub(crate) batch_type: BatchType,
+    pub(crate) consistency: Option<Consistency>,
+    pub(crate) serial_consistency: Option<SerialConsistency>,
+    pub(crate) timestamp: Option<i64>,
+    pub(crate) max_statements: Option<usize>,
+    pub(crate) max_bytes: Option<usize>,
+    pub(crate) split_by_token: bool,
+    pub(crate) read_policy: ScyllaDBTransactionReadPolicy,
+}
+
+impl Default for ScyllaDBTransactionOptions {
+    fn default() -> Self {
+        Self {
+            batch_type: BatchType::Logged,
+            consistency: None,
+            serial_consistency: None,
+            timestamp: None,
+            max_statements: None,
+            max_bytes: None,
+            split_by_token: false,
+            read_policy: ScyllaDBTransactionReadPolicy::PassThrough,
+        }
+    }
+}
+
+impl ScyllaDBTransactionOptions {
+    /// Create the default options, which commit with a logged batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the type of the batch. The default is [BatchType::Logged].
+    pub fn batch_type(mut self, batch_type: BatchType) -> Self {
+        self.batch_type = batch_type;
+        self
+    }
+
+    /// Set the consistency of the batch.
+    pub fn consistency(mut self, consistency: Consistency) -> Self {
+        self.consistency = Some(consistency);
+        self
+    }
+
+    /// Set the serial consistency of the batch, used by conditional statements.
+    pub fn serial_consistency(mut self, serial_consistency: SerialConsistency) -> Self {
+        self.serial_consistency = Some(serial_consistency);
+        self
+    }
+
+    /// Set the timestamp of the batch in microseconds.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Set the maximum number of statements in a batch.
+    pub fn max_statements(mut self, max_statements: usize) -> Self {
+        self.max_statements = Some(max_statements);
+        self
+    }
+
+    /// Set the maximum size in bytes of the serialized arguments of a batch.
+    ///
+    /// Keep it under `batch_size_fail_threshold_in_kb` of the server.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Split an unlogged batch exceeding the limits into several batches grouped by partition token,
+    /// instead of failing when a statement is appended.
+    ///
+    /// The split batches are not applied atomically. Ignored for other batch types.
+    pub fn split_by_token(mut self) -> Self {
+        self.split_by_token = true;
+        self
+    }
+
+    /// Set how reads inside the transaction are handled. The default is [ScyllaDBTransactionReadPolicy::PassThrough].
+    pub fn read_policy(mut self, read_policy: ScyllaDBTransactionReadPolicy) -> Self {
+        self.read_policy = read_policy;
+        self
+    }
+
+    pub(crate) fn exceeds_limits(&self, statements: usize, bytes: usize) -> bool {
+        self.max_statements.is_some_and(|max| statements > max)
+            || self.max_bytes.is_some_and(|max| bytes > max)
+    }
+
+    pub(crate) fn can_split(&self) -> bool {
+        self.split_by_token && self.batch_type == BatchType::Unlogged
+    }
+}
+
 /// Implementation of [sqlx::TransactionManager] for ScyllaDB.
 pub struct ScyllaDBTransactionManager {}
 
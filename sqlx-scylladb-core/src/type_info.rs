@@ -15,138 +15,96 @@ pub enum ScyllaDBTypeInfo {
     Any(UStr),
     /// `ascii` type.
     Ascii,
-    /// array of `ascii` type.
-    AsciiArray,
     /// `boolean` type.
     Boolean,
-    /// array of `boolean` type.
-    BooleanArray,
     /// `blob` type.
     Blob,
-    /// array of `blob` type.
-    BlobArray,
     /// `counter` type.
     Counter,
     /// `decimal` type.
     Decimal,
-    /// array of `decimal` type.
-    DecimalArray,
     /// `date` type.
     Date,
-    /// array of `date` type.
-    DateArray,
     /// `double` type.
     Double,
-    /// array of `double` type.
-    DoubleArray,
     /// `duration` type.
     Duration,
-    /// array of `duration` type.
-    DurationArray,
     /// NULL type.
     Null,
     /// `float` type.
     Float,
-    /// array of `float` type.
-    FloatArray,
     /// `int` type.
     Int,
-    /// array of `int` type.
-    IntArray,
     /// `bigint` type.
     BigInt,
-    /// array of `bigint` type.
-    BigIntArray,
     /// `text` type.
     Text,
-    /// array of `text` type.
-    TextArray,
     /// `timestamp` type.
     Timestamp,
-    /// array of `timestamp` type.
-    TimestampArray,
     /// `inet` type.
     Inet,
-    /// array of `inet` type.
-    InetArray,
     /// `smallint` type.
     SmallInt,
-    /// array of `smallint` type.
-    SmallIntArray,
     /// `tinyint` type.
     TinyInt,
-    /// array of `tinyint` type.
-    TinyIntArray,
     /// `time` type.
     Time,
-    /// array of `time` type.
-    TimeArray,
     /// `timeuuid` type.
     Timeuuid,
-    /// array of `timeuuid` type.
-    TimeuuidArray,
     /// Unset type.
     Unset,
     /// `uuid` type.
     Uuid,
-    /// array of `uuid` type.
-    UuidArray,
-    /// `variant` type.
+    /// `varint` type.
     Variant,
-    /// Any tuple type.
-    Tuple(UStr),
     /// user-defined type.
     UserDefinedType(UStr),
-    /// array of user-defined type.
-    UserDefinedTypeArray(UStr),
-    /// map type of `ascii` and `ascii`.
-    AsciiAsciiMap,
-    /// map type of `ascii` and `text`.
-    AsciiTextMap,
-    /// map type of `ascii` and `boolean`.
-    AsciiBooleanMap,
-    /// map type of `ascii` and `tinyint`.
-    AsciiTinyIntMap,
-    /// map type of `ascii` and `smallint`.
-    AsciiSmallIntMap,
-    /// map type of `ascii` and `int`.
-    AsciiIntMap,
-    /// map type of `ascii` and `bigint`.
-    AsciiBigIntMap,
-    /// map type of `ascii` and `float`.
-    AsciiFloatMap,
-    /// map type of `ascii` and `double`.
-    AsciiDoubleMap,
-    /// map type of `ascii` and `uuid`.
-    AsciiUuidMap,
-    /// map type of `ascii` and `timeuuid`.
-    AsciiTimeuuidMap,
-    /// map type of `ascii` and `inet`.
-    AsciiInetMap,
-    /// map type of `text` and `ascii`.
-    TextAsciiMap,
-    /// map type of `text` and `text`.
-    TextTextMap,
-    /// map type of `text` and `boolean`.
-    TextBooleanMap,
-    /// map type of `text` and `tinyint`.
-    TextTinyIntMap,
-    /// map type of `text` and `smallint`.
-    TextSmallIntMap,
-    /// map type of `text` and `int`.
-    TextIntMap,
-    /// map type of `text` and `bigint`.
-    TextBigIntMap,
-    /// map type of `text` and `float`.
-    TextFloatMap,
-    /// map type of `text` and `double`.
-    TextDoubleMap,
-    /// map type of `text` and `uuid`.
-    TextUuidMap,
-    /// map type of `text` and `timeuuid`.
-    TextTimeuuidMap,
-    /// map type of `text` and `inet`.
-    TextInetMap,
+    /// `list` type such as `LIST<INT>`.
+    List {
+        /// element type.
+        element: Box<ScyllaDBTypeInfo>,
+        /// cached type name.
+        name: UStr,
+    },
+    /// `set` type such as `SET<INT>`.
+    Set {
+        /// element type.
+        element: Box<ScyllaDBTypeInfo>,
+        /// cached type name.
+        name: UStr,
+    },
+    /// `map` type such as `MAP<TEXT, INT>`.
+    Map {
+        /// key type.
+        key: Box<ScyllaDBTypeInfo>,
+        /// value type.
+        value: Box<ScyllaDBTypeInfo>,
+        /// cached type name.
+        name: UStr,
+    },
+    /// `frozen` type such as `FROZEN<LIST<INT>>`.
+    Frozen {
+        /// frozen type.
+        inner: Box<ScyllaDBTypeInfo>,
+        /// cached type name.
+        name: UStr,
+    },
+    /// `tuple` type such as `TUPLE<INT, TEXT>`.
+    Tuple {
+        /// item types.
+        items: Vec<ScyllaDBTypeInfo>,
+        /// cached type name.
+        name: UStr,
+    },
+    /// `vector` type such as `VECTOR<FLOAT, 3>`.
+    Vector {
+        /// element type.
+        element: Box<ScyllaDBTypeInfo>,
+        /// number of dimensions.
+        dimensions: u16,
+        /// cached type name.
+        name: UStr,
+    },
 }
 
 impl TypeInfo for ScyllaDBTypeInfo {
@@ -158,72 +116,34 @@ impl TypeInfo for ScyllaDBTypeInfo {
         match self {
             Self::Any(name) => name,
             Self::Ascii => "ASCII",
-            Self::AsciiArray => "ASCII[]",
             Self::Text => "TEXT",
-            Self::TextArray => "TEXT[]",
             Self::Boolean => "BOOLEAN",
-            Self::BooleanArray => "BOOLEAN[]",
             Self::Blob => "BLOB",
-            Self::BlobArray => "BLOB[]",
             Self::BigInt => "BIGINT",
-            Self::BigIntArray => "BIGINT[]",
             Self::Counter => "COUNTER",
             Self::Decimal => "DECIMAL",
-            Self::DecimalArray => "DECIMAL[]",
             Self::Date => "DATE",
-            Self::DateArray => "DATE[]",
             Self::Double => "DOUBLE",
-            Self::DoubleArray => "DOUBLE[]",
             Self::Duration => "DURATION",
-            Self::DurationArray => "DURATION[]",
             Self::Null => "NULL",
             Self::Float => "FLOAT",
-            Self::FloatArray => "FLOAT[]",
             Self::Int => "INT",
-            Self::IntArray => "INT[]",
             Self::Timestamp => "TIMESTAMP",
-            Self::TimestampArray => "TIMESTAMP[]",
             Self::Inet => "INET",
-            Self::InetArray => "INET[]",
             Self::SmallInt => "SMALLINT",
-            Self::SmallIntArray => "SMALLINT[]",
             Self::TinyInt => "TINYINT",
-            Self::TinyIntArray => "TINYINT[]",
             Self::Time => "TIME",
-            Self::TimeArray => "TIME[]",
             Self::Unset => "UNSET",
             Self::Uuid => "UUID",
-            Self::UuidArray => "UUID[]",
             Self::Timeuuid => "TIMEUUID",
-            Self::TimeuuidArray => "TIMEUUID[]",
             Self::Variant => "VARIANT",
-            Self::Tuple(name) => name,
             Self::UserDefinedType(name) => name,
-            Self::UserDefinedTypeArray(name) => name,
-            Self::AsciiAsciiMap => "MAP<ASCII, ASCII>",
-            Self::AsciiTextMap => "MAP<ASCII, TEXT>",
-            Self::AsciiBooleanMap => "MAP<ASCII, BOOLEAN>",
-            Self::AsciiTinyIntMap => "MAP<ASCII, TINYINT>",
-            Self::AsciiSmallIntMap => "MAP<ASCII, SMALLINT>",
-            Self::AsciiIntMap => "MAP<ASCII, INT>",
-            Self::AsciiBigIntMap => "MAP<ASCII, BIGINT>",
-            Self::AsciiFloatMap => "MAP<ASCII, FLOAT>",
-            Self::AsciiDoubleMap => "MAP<ASCII, DOUBLE>",
-            Self::AsciiUuidMap => "MAP<ASCII, UUID>",
-            Self::AsciiTimeuuidMap => "MAP<ASCII, TIMEUUID>",
-            Self::AsciiInetMap => "MAP<ASCII, INET>",
-            Self::TextAsciiMap => "MAP<TEXT, ASCII>",
-            Self::TextTextMap => "MAP<TEXT, TEXT>",
-            Self::TextBooleanMap => "MAP<TEXT, BOOLEAN>",
-            Self::TextTinyIntMap => "MAP<TEXT, TINYINT>",
-            Self::TextSmallIntMap => "MAP<TEXT, SMALLINT>",
-            Self::TextIntMap => "MAP<TEXT, INT>",
-            Self::TextBigIntMap => "MAP<TEXT, BIGINT>",
-            Self::TextFloatMap => "MAP<TEXT, FLOAT>",
-            Self::TextDoubleMap => "MAP<TEXT, DOUBLE>",
-            Self::TextUuidMap => "MAP<TEXT, UUID>",
-            Self::TextTimeuuidMap => "MAP<TEXT, TIMEUUID>",
-            Self::TextInetMap => "MAP<TEXT, INET>",
+            Self::List { name, .. } => name,
+            Self::Set { name, .. } => name,
+            Self::Map { name, .. } => name,
+            Self::Frozen { name, .. } => name,
+            Self::Tuple { name, .. } => name,
+            Self::Vector { name, .. } => name,
         }
     }
 
@@ -231,60 +151,78 @@ impl TypeInfo for ScyllaDBTypeInfo {
     where
         Self: Sized,
     {
-        match self {
-            Self::Ascii | Self::Text => *other == Self::Ascii || *other == Self::Text,
-            Self::AsciiArray | Self::TextArray => {
-                *other == Self::AsciiArray || *other == Self::TextArray
-            }
-            Self::BigInt => *other == Self::Counter || *other == Self::BigInt,
-            Self::Uuid => *other == Self::Uuid || *other == Self::Timeuuid,
-            Self::UuidArray => *other == Self::UuidArray || *other == Self::TimeuuidArray,
-            Self::AsciiAsciiMap | Self::AsciiTextMap | Self::TextTextMap | Self::TextAsciiMap => {
-                *other == Self::AsciiAsciiMap
-                    || *other == Self::AsciiTextMap
-                    || *other == Self::TextTextMap
-                    || *other == Self::TextAsciiMap
-            }
-            Self::AsciiBooleanMap | Self::TextBooleanMap => {
-                *other == Self::AsciiBooleanMap || *other == Self::TextBooleanMap
-            }
-            Self::AsciiTinyIntMap | Self::TextTinyIntMap => {
-                *other == Self::AsciiTinyIntMap || *other == Self::TextTinyIntMap
-            }
-            Self::AsciiSmallIntMap | Self::TextSmallIntMap => {
-                *other == Self::AsciiSmallIntMap || *other == Self::TextSmallIntMap
-            }
-            Self::AsciiIntMap | Self::TextIntMap => {
-                *other == Self::AsciiIntMap || *other == Self::TextIntMap
-            }
-            Self::AsciiBigIntMap | Self::TextBigIntMap => {
-                *other == Self::AsciiBigIntMap || *other == Self::TextBigIntMap
-            }
-            Self::AsciiFloatMap | Self::TextFloatMap => {
-                *other == Self::AsciiFloatMap || *other == Self::TextFloatMap
-            }
-            Self::AsciiDoubleMap | Self::TextDoubleMap => {
-                *other == Self::AsciiDoubleMap || *other == Self::TextDoubleMap
-            }
-            Self::AsciiUuidMap
-            | Self::TextUuidMap
-            | Self::AsciiTimeuuidMap
-            | Self::TextTimeuuidMap => {
-                *other == Self::AsciiUuidMap
-                    || *other == Self::TextUuidMap
-                    || *other == Self::AsciiTimeuuidMap
-                    || *other == Self::TextTimeuuidMap
-            }
-            Self::AsciiInetMap | Self::TextInetMap => {
-                *other == Self::AsciiInetMap || *other == Self::TextInetMap
-            }
-            Self::Tuple(typ) => {
-                if let Self::Tuple(other_typ) = other {
-                    typ.replace("ASCII", "TEXT") == other_typ.replace("ASCII", "TEXT")
-                } else {
-                    self == other
+        match (self, other) {
+            (Self::Frozen { inner, .. }, _) => inner.type_compatible(other),
+            (_, Self::Frozen { inner, .. }) => self.type_compatible(inner),
+            (Self::Ascii | Self::Text, Self::Ascii | Self::Text) => true,
+            (Self::BigInt | Self::Counter, Self::BigInt | Self::Counter) => true,
+            (Self::Uuid | Self::Timeuuid, Self::Uuid | Self::Timeuuid) => true,
+            // A list type may also be bound to or read from a set column, but not vice versa.
+            (
+                Self::List { element, .. },
+                Self::List {
+                    element: other_element,
+                    ..
                 }
+                | Self::Set {
+                    element: other_element,
+                    ..
+                },
+            )
+            | (
+                Self::Set { element, .. },
+                Self::Set {
+                    element: other_element,
+                    ..
+                },
+            ) => element.type_compatible(other_element),
+            // The number of elements is checked when the value is encoded or decoded.
+            (
+                Self::List { element, .. },
+                Self::Vector {
+                    element: other_element,
+                    ..
+                },
+            )
+            | (
+                Self::Vector { element, .. },
+                Self::List {
+                    element: other_element,
+                    ..
+                },
+            ) => element.type_compatible(other_element),
+            (
+                Self::Map { key, value, .. },
+                Self::Map {
+                    key: other_key,
+                    value: other_value,
+                    ..
+                },
+            ) => key.type_compatible(other_key) && value.type_compatible(other_value),
+            (
+                Self::Tuple { items, .. },
+                Self::Tuple {
+                    items: other_items, ..
+                },
+            ) => {
+                items.len() == other_items.len()
+                    && items
+                        .iter()
+                        .zip(other_items)
+                        .all(|(item, other_item)| item.type_compatible(other_item))
             }
+            (
+                Self::Vector {
+                    element,
+                    dimensions,
+                    ..
+                },
+                Self::Vector {
+                    element: other_element,
+                    dimensions: other_dimensions,
+                    ..
+                },
+            ) => dimensions == other_dimensions && element.type_compatible(other_element),
             _ => self == other,
         }
     }
@@ -293,7 +231,7 @@ impl TypeInfo for ScyllaDBTypeInfo {
 static ANY_TYPES: LazyLock<RwLock<Vec<(ColumnType<'static>, UStr)>>> =
     LazyLock::new(|| RwLock::new(Vec::new()));
 
-///
+/// Registers a column type to be reported as [`ScyllaDBTypeInfo::Any`] with the given name.
 pub fn register_any_type(
     column_type: ColumnType<'static>,
     name: UStr,
@@ -334,6 +272,13 @@ macro_rules! column_type_not_supported {
 
 impl ScyllaDBTypeInfo {
     pub(crate) fn from_column_type(column_type: &ColumnType) -> Result<Self, ScyllaDBError> {
+        // Registered types take precedence over the generic collection types.
+        if !matches!(column_type, ColumnType::Native(_))
+            && let Some(type_info) = get_any_type(column_type)?
+        {
+            return Ok(type_info);
+        }
+
         let type_info = match column_type {
             ColumnType::Native(native_type) => match native_type {
                 NativeType::Ascii => Self::Ascii,
@@ -358,106 +303,100 @@ impl ScyllaDBTypeInfo {
                 NativeType::Varint => Self::Variant,
                 _ => column_type_not_supported!(column_type),
             },
-            ColumnType::Collection { frozen: _, typ } => match typ {
-                CollectionType::List(inner) | CollectionType::Set(inner) => match &**inner {
-                    ColumnType::Native(native_type) => match native_type {
-                        NativeType::Ascii => Self::AsciiArray,
-                        NativeType::Boolean => Self::BooleanArray,
-                        NativeType::Blob => Self::BlobArray,
-                        NativeType::Counter => column_type_not_supported!(column_type),
-                        NativeType::Date => Self::DateArray,
-                        NativeType::Decimal => Self::DecimalArray,
-                        NativeType::Double => Self::DoubleArray,
-                        NativeType::Duration => Self::DurationArray,
-                        NativeType::Float => Self::FloatArray,
-                        NativeType::Int => Self::IntArray,
-                        NativeType::BigInt => Self::BigIntArray,
-                        NativeType::Text => Self::TextArray,
-                        NativeType::Timestamp => Self::TimestampArray,
-                        NativeType::Inet => Self::InetArray,
-                        NativeType::SmallInt => Self::SmallIntArray,
-                        NativeType::TinyInt => Self::TinyIntArray,
-                        NativeType::Time => Self::TimeArray,
-                        NativeType::Timeuuid => Self::TimeuuidArray,
-                        NativeType::Uuid => Self::UuidArray,
-                        NativeType::Varint => column_type_not_supported!(column_type),
-                        _ => column_type_not_supported!(column_type),
-                    },
-                    ColumnType::UserDefinedType {
-                        frozen: _,
-                        definition,
-                    } => {
-                        let type_name = format!("{}[]", definition.name);
-                        let type_name = UStr::new(&type_name);
-                        Self::UserDefinedTypeArray(type_name)
+            ColumnType::Collection { frozen, typ } => {
+                let type_info = match typ {
+                    CollectionType::List(element) => Self::list(Self::from_column_type(element)?),
+                    CollectionType::Set(element) => Self::set(Self::from_column_type(element)?),
+                    CollectionType::Map(key, value) => {
+                        Self::map(Self::from_column_type(key)?, Self::from_column_type(value)?)
                     }
                     _ => column_type_not_supported!(column_type),
-                },
-                CollectionType::Map(key_type, value_type) => match &**key_type {
-                    ColumnType::Native(key_native_type) => match key_native_type {
-                        NativeType::Ascii => match &**value_type {
-                            ColumnType::Native(value_native_type) => match value_native_type {
-                                NativeType::Ascii => Self::AsciiAsciiMap,
-                                NativeType::Text => Self::AsciiTextMap,
-                                NativeType::Boolean => Self::AsciiBooleanMap,
-                                NativeType::Double => Self::AsciiDoubleMap,
-                                NativeType::Float => Self::AsciiFloatMap,
-                                NativeType::Int => Self::AsciiIntMap,
-                                NativeType::BigInt => Self::AsciiBigIntMap,
-                                NativeType::SmallInt => Self::AsciiSmallIntMap,
-                                NativeType::TinyInt => Self::AsciiTinyIntMap,
-                                NativeType::Timeuuid => Self::AsciiTimeuuidMap,
-                                NativeType::Uuid => Self::AsciiUuidMap,
-                                NativeType::Inet => Self::AsciiInetMap,
-                                _ => column_type_not_supported!(column_type),
-                            },
-                            _ => column_type_not_supported!(column_type),
-                        },
-                        NativeType::Text => match &**value_type {
-                            ColumnType::Native(value_native_type) => match value_native_type {
-                                NativeType::Ascii => Self::TextAsciiMap,
-                                NativeType::Text => Self::TextTextMap,
-                                NativeType::Boolean => Self::TextBooleanMap,
-                                NativeType::Double => Self::TextDoubleMap,
-                                NativeType::Float => Self::TextFloatMap,
-                                NativeType::Int => Self::TextIntMap,
-                                NativeType::BigInt => Self::TextBigIntMap,
-                                NativeType::SmallInt => Self::TextSmallIntMap,
-                                NativeType::TinyInt => Self::TextTinyIntMap,
-                                NativeType::Timeuuid => Self::TextTimeuuidMap,
-                                NativeType::Uuid => Self::TextUuidMap,
-                                NativeType::Inet => Self::TextInetMap,
-                                _ => column_type_not_supported!(column_type),
-                            },
-                            _ => column_type_not_supported!(column_type),
-                        },
-                        _ => column_type_not_supported!(column_type),
-                    },
-                    _ => column_type_not_supported!(column_type),
-                },
-                _ => column_type_not_supported!(column_type),
-            },
-            ColumnType::UserDefinedType {
-                frozen: _,
-                definition,
-            } => {
+                };
+                if *frozen {
+                    Self::frozen(type_info)
+                } else {
+                    type_info
+                }
+            }
+            ColumnType::UserDefinedType { frozen, definition } => {
                 let type_name = UStr::new(&definition.name);
-                Self::UserDefinedType(type_name)
+                let type_info = Self::UserDefinedType(type_name);
+                if *frozen {
+                    Self::frozen(type_info)
+                } else {
+                    type_info
+                }
             }
             ColumnType::Tuple(items) => {
-                let mut type_infos = Vec::with_capacity(items.capacity());
+                let mut type_infos = Vec::with_capacity(items.len());
                 for item in items {
                     let type_info = Self::from_column_type(item)?;
                     type_infos.push(type_info);
                 }
-                let type_name = tuple_type_name(&type_infos);
-                Self::Tuple(type_name)
+                Self::tuple(type_infos)
+            }
+            ColumnType::Vector { typ, dimensions } => {
+                Self::vector(Self::from_column_type(typ)?, *dimensions)
             }
             _ => column_type_not_supported!(column_type),
         };
 
         Ok(type_info)
     }
+
+    /// Create the type info of `list<element>`.
+    pub fn list(element: ScyllaDBTypeInfo) -> Self {
+        let name = UStr::new(&format!("LIST<{}>", element.name()));
+        Self::List {
+            element: Box::new(element),
+            name,
+        }
+    }
+
+    /// Create the type info of `set<element>`.
+    pub fn set(element: ScyllaDBTypeInfo) -> Self {
+        let name = UStr::new(&format!("SET<{}>", element.name()));
+        Self::Set {
+            element: Box::new(element),
+            name,
+        }
+    }
+
+    /// Create the type info of `map<key, value>`.
+    pub fn map(key: ScyllaDBTypeInfo, value: ScyllaDBTypeInfo) -> Self {
+        let name = UStr::new(&format!("MAP<{}, {}>", key.name(), value.name()));
+        Self::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+            name,
+        }
+    }
+
+    /// Create the type info of `frozen<inner>`.
+    pub fn frozen(inner: ScyllaDBTypeInfo) -> Self {
+        let name = UStr::new(&format!("FROZEN<{}>", inner.name()));
+        Self::Frozen {
+            inner: Box::new(inner),
+            name,
+        }
+    }
+
+    /// Create the type info of `tuple<items...>`.
+    pub fn tuple(items: Vec<ScyllaDBTypeInfo>) -> Self {
+        let names: Vec<&str> = items.iter().map(|item| item.name()).collect();
+        let name = UStr::new(&format!("TUPLE<{}>", names.join(", ")));
+        Self::Tuple { items, name }
+    }
+
+    /// Create the type info of `vector<element, dimensions>`.
+    pub fn vector(element: ScyllaDBTypeInfo, dimensions: u16) -> Self {
+        let name = UStr::new(&format!("VECTOR<{}, {}>", element.name(), dimensions));
+        Self::Vector {
+            element: Box::new(element),
+            dimensions,
+            name,
+        }
+    }
 }
 
 impl Display for ScyllaDBTypeInfo {
@@ -466,15 +405,92 @@ impl Display for ScyllaDBTypeInfo {
     }
 }
 
-pub(crate) fn tuple_type_name(type_infos: &[ScyllaDBTypeInfo]) -> UStr {
-    let mut type_name = String::from("TUPLE<");
-    for (i, type_info) in type_infos.iter().enumerate() {
-        if i > 0 {
-            type_name.push_str(", ");
+#[cfg(test)]
+mod tests {
+    use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+    use sqlx_core::{ext::ustr::UStr, type_info::TypeInfo};
+
+    use crate::ScyllaDBTypeInfo;
+
+    fn list(element: ColumnType<'static>, frozen: bool) -> ColumnType<'static> {
+        ColumnType::Collection {
+            frozen,
+            typ: CollectionType::List(Box::new(element)),
         }
-        let name = type_info.name();
-        type_name.push_str(name);
     }
-    type_name.push_str(">");
-    UStr::new(&type_name)
+
+    fn set(element: ColumnType<'static>, frozen: bool) -> ColumnType<'static> {
+        ColumnType::Collection {
+            frozen,
+            typ: CollectionType::Set(Box::new(element)),
+        }
+    }
+
+    #[test]
+    fn it_can_resolve_nested_collection_types() -> Result<(), crate::ScyllaDBError> {
+        let column_type = list(list(ColumnType::Native(NativeType::Int), true), false);
+        let type_info = ScyllaDBTypeInfo::from_column_type(&column_type)?;
+        assert_eq!("LIST<FROZEN<LIST<INT>>>", type_info.name());
+        assert_eq!(
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::list(
+                ScyllaDBTypeInfo::Int
+            ))),
+            type_info
+        );
+
+        let column_type = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Text)),
+                Box::new(set(ColumnType::Native(NativeType::Uuid), true)),
+            ),
+        };
+        let type_info = ScyllaDBTypeInfo::from_column_type(&column_type)?;
+        assert_eq!("MAP<TEXT, FROZEN<SET<UUID>>>", type_info.name());
+
+        let column_type = ColumnType::Tuple(vec![
+            ColumnType::Native(NativeType::Ascii),
+            list(ColumnType::Native(NativeType::Timeuuid), true),
+        ]);
+        let type_info = ScyllaDBTypeInfo::from_column_type(&column_type)?;
+        assert_eq!("TUPLE<ASCII, FROZEN<LIST<TIMEUUID>>>", type_info.name());
+
+        let column_type = ColumnType::Vector {
+            typ: Box::new(ColumnType::Native(NativeType::Float)),
+            dimensions: 3,
+        };
+        let type_info = ScyllaDBTypeInfo::from_column_type(&column_type)?;
+        assert_eq!("VECTOR<FLOAT, 3>", type_info.name());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_check_nested_type_compatibility() {
+        let column = ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::set(
+            ScyllaDBTypeInfo::Ascii,
+        )));
+        let rust = ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text));
+        assert!(rust.type_compatible(&column));
+        assert!(!column.type_compatible(&rust));
+
+        let rust = ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Int));
+        assert!(!rust.type_compatible(&column));
+
+        let column = ScyllaDBTypeInfo::tuple(vec![
+            ScyllaDBTypeInfo::Timeuuid,
+            ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::UserDefinedType(UStr::new("my_type"))),
+        ]);
+        let rust = ScyllaDBTypeInfo::tuple(vec![
+            ScyllaDBTypeInfo::Uuid,
+            ScyllaDBTypeInfo::UserDefinedType(UStr::new("my_type")),
+        ]);
+        assert!(column.type_compatible(&rust));
+        let rust = ScyllaDBTypeInfo::tuple(vec![ScyllaDBTypeInfo::Uuid]);
+        assert!(!column.type_compatible(&rust));
+
+        let column = ScyllaDBTypeInfo::vector(ScyllaDBTypeInfo::Float, 3);
+        assert!(column.type_compatible(&ScyllaDBTypeInfo::vector(ScyllaDBTypeInfo::Float, 3)));
+        assert!(!column.type_compatible(&ScyllaDBTypeInfo::vector(ScyllaDBTypeInfo::Float, 4)));
+    }
 }
@@ -33,7 +33,7 @@ macro_rules! impl_array_type {
     ($typ:ty, $typ_info:path, $arg_typ:path) => {
         impl $crate::ScyllaDBHasArrayType for $typ {
             fn array_type_info() -> $crate::ScyllaDBTypeInfo {
-                $typ_info
+                $crate::ScyllaDBTypeInfo::list($typ_info)
             }
         }
 
@@ -93,40 +93,101 @@ macro_rules! impl_array_type {
     };
 }
 
-macro_rules! impl_map_type {
-    ($key_typ:ty, $value_typ:ty, $typ_info:path, $arg_typ:path) => {
-        impl ::sqlx_core::types::Type<$crate::ScyllaDB>
-            for ::std::collections::HashMap<$key_typ, $value_typ>
-        {
+/// Implement a type that the driver cannot serialize itself.
+///
+/// The value is stored as is in the argument and converted when it is serialized.
+/// It is decoded as `$cql_typ` and converted with `$from_cql`, which fails when out of range.
+#[cfg(any(feature = "jiff-02", feature = "rust_decimal-1"))]
+macro_rules! impl_converted_type {
+    ($typ:ty, $cql_typ:ty, $typ_info:path, $arg_typ:path, $array_arg_typ:path, $from_cql:path) => {
+        impl ::sqlx_core::types::Type<$crate::ScyllaDB> for $typ {
             fn type_info() -> $crate::ScyllaDBTypeInfo {
                 $typ_info
             }
         }
 
-        impl ::sqlx_core::decode::Decode<'_, $crate::ScyllaDB>
-            for ::std::collections::HashMap<$key_typ, $value_typ>
-        {
+        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for $typ {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                let argument = $arg_typ(self.clone());
+                buf.push(argument);
+
+                Ok(::sqlx_core::encode::IsNull::No)
+            }
+        }
+
+        impl ::sqlx_core::decode::Decode<'_, $crate::ScyllaDB> for $typ {
             fn decode(
                 value: $crate::ScyllaDBValueRef<'_>,
             ) -> Result<Self, ::sqlx_core::error::BoxDynError> {
-                let val: Self = value.deserialize()?;
+                let val: $cql_typ = value.deserialize()?;
+                let val = $from_cql(&val)?;
                 Ok(val)
             }
         }
 
-        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB>
-            for ::std::collections::HashMap<$key_typ, $value_typ>
-        {
+        impl $crate::ScyllaDBHasArrayType for $typ {
+            fn array_type_info() -> $crate::ScyllaDBTypeInfo {
+                $crate::ScyllaDBTypeInfo::list($typ_info)
+            }
+        }
+
+        impl<const N: usize> ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for [$typ; N] {
             fn encode_by_ref(
                 &self,
                 buf: &mut $crate::ScyllaDBArgumentBuffer,
             ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
-                let argument = $arg_typ(self.clone());
+                <_ as ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB>>::encode_by_ref(
+                    self.as_slice(),
+                    buf,
+                )
+            }
+        }
+
+        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for [$typ] {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                let argument = $array_arg_typ(self.to_vec());
                 buf.push(argument);
 
                 Ok(::sqlx_core::encode::IsNull::No)
             }
         }
+
+        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for &[$typ] {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                <_ as ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB>>::encode_by_ref(*self, buf)
+            }
+        }
+
+        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for ::std::vec::Vec<$typ> {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                <_ as ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB>>::encode_by_ref(
+                    self.as_slice(),
+                    buf,
+                )
+            }
+        }
+
+        impl ::sqlx_core::decode::Decode<'_, $crate::ScyllaDB> for ::std::vec::Vec<$typ> {
+            fn decode(
+                value: $crate::ScyllaDBValueRef<'_>,
+            ) -> Result<Self, ::sqlx_core::error::BoxDynError> {
+                let val: ::std::vec::Vec<$cql_typ> = value.deserialize()?;
+                let val = val.iter().map($from_cql).collect::<Result<Self, _>>()?;
+                Ok(val)
+            }
+        }
     };
 }
 
@@ -141,6 +202,7 @@ pub mod float;
 pub mod inet;
 pub mod int;
 pub mod map;
+pub mod set;
 pub mod text;
 pub mod time;
 pub mod timestamp;
@@ -148,6 +210,20 @@ pub mod tuple;
 pub mod unset;
 pub mod user_defined_type;
 pub mod uuid;
+pub mod varint;
+
+/// Encode the elements of a collection as arguments.
+pub(crate) fn encode_elements<'a, 'q, T>(
+    elements: impl IntoIterator<Item = &'a T>,
+) -> ::std::result::Result<::std::vec::Vec<crate::ScyllaDBArgument>, ::sqlx_core::error::BoxDynError>
+where
+    T: ::sqlx_core::encode::Encode<'q, crate::ScyllaDB> + 'a,
+{
+    elements
+        .into_iter()
+        .map(crate::ScyllaDBArgument::encode_element)
+        .collect()
+}
 
 #[cfg(test)]
 fn serialize_value<T>(
@@ -1,6 +1,16 @@
-use sqlx_core::{type_info::TypeInfo, types::Type};
+use scylla::deserialize::value::DeserializeValue;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    type_info::TypeInfo,
+    types::Type,
+};
 
-use crate::{ScyllaDB, ScyllaDBTypeInfo};
+use crate::{
+    ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+    types::encode_elements,
+};
 
 /// Provides information necessary to encode and decode ScyllaDB arrays as compatible Rust types.
 pub trait ScyllaDBHasArrayType {
@@ -75,3 +85,120 @@ where
         T::array_compatible(ty)
     }
 }
+
+impl<T> ScyllaDBHasArrayType for Vec<T>
+where
+    T: ScyllaDBHasArrayType,
+{
+    fn array_type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::list(T::array_type_info())
+    }
+}
+
+impl<'q, T> Encode<'q, ScyllaDB> for Vec<Vec<T>>
+where
+    T: ScyllaDBHasArrayType,
+    Vec<T>: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::List(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T> Decode<'r, ScyllaDB> for Vec<Vec<T>>
+where
+    T: ScyllaDBHasArrayType,
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+
+    use sqlx_core::{
+        decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr, types::Type,
+    };
+
+    use crate::{
+        ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+        types::serialize_value,
+    };
+
+    #[test]
+    fn it_can_encode_nested_list() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(vec![vec![1i32, 2], vec![3]], &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            vec![HashMap::from([(String::from("Hello"), 7i64)])],
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            vec![(7i32, String::from("Hello")), (11, String::from("World!"))],
+            &mut buf,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_nested_list() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::List(Box::new(ColumnType::Collection {
+                frozen: true,
+                typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Int))),
+            })),
+        };
+        let raw_value = serialize_value(&vec![vec![1i32, 2], vec![3]], &column_type)?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_nested_list"),
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::list(
+                ScyllaDBTypeInfo::Int,
+            ))),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: Vec<Vec<i32>> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, vec![vec![1, 2], vec![3]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_has_nested_list_type_info() {
+        assert_eq!(
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Int)),
+            <Vec<Vec<i32>> as Type<ScyllaDB>>::type_info()
+        );
+        assert_eq!(
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::tuple(vec![
+                ScyllaDBTypeInfo::Int,
+                ScyllaDBTypeInfo::Text
+            ])),
+            <Vec<(i32, String)> as Type<ScyllaDB>>::type_info()
+        );
+        assert!(<Vec<Vec<String>> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::set(
+                ScyllaDBTypeInfo::Ascii
+            )))
+        ));
+        assert!(
+            <HashMap<String, Vec<String>> as Type<ScyllaDB>>::compatible(&ScyllaDBTypeInfo::map(
+                ScyllaDBTypeInfo::Text,
+                ScyllaDBTypeInfo::frozen(ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text))
+            ))
+        );
+    }
+}
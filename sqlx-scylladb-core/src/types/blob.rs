@@ -90,7 +90,7 @@ impl<const N: usize> Type<ScyllaDB> for [[u8; N]] {
 
 impl Type<ScyllaDB> for Vec<Vec<u8>> {
     fn type_info() -> ScyllaDBTypeInfo {
-        ScyllaDBTypeInfo::BlobArray
+        ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Blob)
     }
 }
 
@@ -283,7 +283,7 @@ mod secrecy {
 
     impl Type<ScyllaDB> for Vec<SecretVec<u8>> {
         fn type_info() -> ScyllaDBTypeInfo {
-            ScyllaDBTypeInfo::BlobArray
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Blob)
         }
     }
 
@@ -575,7 +575,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_blob"),
-            ScyllaDBTypeInfo::BlobArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Blob),
             &raw_value,
             &column_type,
         );
@@ -664,7 +664,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_blob"),
-                ScyllaDBTypeInfo::BlobArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Blob),
                 &raw_value,
                 &column_type,
             );
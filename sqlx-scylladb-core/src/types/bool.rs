@@ -4,7 +4,7 @@ impl_type!(bool, ScyllaDBTypeInfo::Boolean, ScyllaDBArgument::Boolean);
 
 impl_array_type!(
     bool,
-    ScyllaDBTypeInfo::BooleanArray,
+    ScyllaDBTypeInfo::Boolean,
     ScyllaDBArgument::BooleanArray
 );
 
@@ -63,7 +63,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_boolean"),
-            ScyllaDBTypeInfo::TextArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text),
             &raw_value,
             &column_type,
         );
@@ -1,7 +1,41 @@
 use scylla::value::Counter;
-use sqlx_core::{decode::Decode, error::BoxDynError, types::Type};
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
 
-use crate::{ScyllaDB, ScyllaDBTypeInfo, ScyllaDBValueRef};
+use crate::{
+    ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+};
+
+/// The amount added to a `counter` column, as in `UPDATE ... SET my_counter = my_counter + ?`.
+///
+/// A negative value decrements the counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CounterDelta(pub i64);
+
+impl From<i64> for CounterDelta {
+    fn from(value: i64) -> Self {
+        Self(value)
+    }
+}
+
+impl Type<ScyllaDB> for CounterDelta {
+    fn type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::Counter
+    }
+}
+
+impl Encode<'_, ScyllaDB> for CounterDelta {
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::Counter(Counter(self.0));
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
 
 impl Type<ScyllaDB> for Counter {
     fn type_info() -> ScyllaDBTypeInfo {
@@ -23,9 +57,36 @@ mod tests {
         value::Counter,
     };
 
-    use sqlx_core::{decode::Decode, error::BoxDynError, ext::ustr::UStr};
+    use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
 
-    use crate::{ScyllaDB, ScyllaDBTypeInfo, ScyllaDBValueRef, types::serialize_value};
+    use crate::{
+        CounterDelta, ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo,
+        ScyllaDBValueRef, types::serialize_value,
+    };
+
+    #[test]
+    fn it_can_encode_counter_delta() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(CounterDelta(2), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(CounterDelta::from(-3), &mut buf)?;
+
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Counter);
+        for argument in buf.iter() {
+            let _ = serialize_value(argument, &column_type)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_does_not_coerce_integers_to_counter() {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Counter);
+        assert!(serialize_value(&ScyllaDBArgument::Int(2), &column_type).is_err());
+
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::BigInt);
+        assert!(serialize_value(&ScyllaDBArgument::Counter(Counter(2)), &column_type).is_err());
+    }
 
     #[test]
     fn it_can_decode_counter() -> Result<(), BoxDynError> {
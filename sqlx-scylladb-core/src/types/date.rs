@@ -6,7 +6,7 @@ impl_type!(CqlDate, ScyllaDBTypeInfo::Date, ScyllaDBArgument::CqlDate);
 
 impl_array_type!(
     CqlDate,
-    ScyllaDBTypeInfo::DateArray,
+    ScyllaDBTypeInfo::Date,
     ScyllaDBArgument::CqlDateArray
 );
 
@@ -20,7 +20,7 @@ pub mod chrono {
 
     impl_array_type!(
         chrono_04::NaiveDate,
-        crate::ScyllaDBTypeInfo::DateArray,
+        crate::ScyllaDBTypeInfo::Date,
         crate::ScyllaDBArgument::ChronoNaiveDateArray
     );
 }
@@ -35,11 +35,43 @@ pub mod time {
 
     impl_array_type!(
         time_03::Date,
-        crate::ScyllaDBTypeInfo::DateArray,
+        crate::ScyllaDBTypeInfo::Date,
         crate::ScyllaDBArgument::DateArray
     );
 }
 
+#[cfg(feature = "jiff-02")]
+pub mod jiff {
+    use jiff_02::{SignedDuration, civil::Date};
+    use scylla::value::CqlDate;
+
+    impl_converted_type!(
+        Date,
+        CqlDate,
+        crate::ScyllaDBTypeInfo::Date,
+        crate::ScyllaDBArgument::JiffDate,
+        crate::ScyllaDBArgument::JiffDateArray,
+        from_cql_date
+    );
+
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// The epoch of `date` values, which are stored as days since it offset by 2^31.
+    const EPOCH: Date = Date::constant(1970, 1, 1);
+
+    /// Convert a [Date] to a [CqlDate].
+    pub(crate) fn to_cql_date(date: &Date) -> CqlDate {
+        let days = date.duration_since(EPOCH).as_secs() / SECONDS_PER_DAY;
+        CqlDate(((1i64 << 31) + days) as u32)
+    }
+
+    /// Convert a [CqlDate] to a [Date], failing when it is out of range.
+    pub(crate) fn from_cql_date(date: &CqlDate) -> Result<Date, jiff_02::Error> {
+        let days = date.0 as i64 - (1i64 << 31);
+        EPOCH.checked_add(SignedDuration::from_secs(days * SECONDS_PER_DAY))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{rc::Rc, sync::Arc};
@@ -104,7 +136,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_date"),
-            ScyllaDBTypeInfo::DateArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Date),
             &raw_value,
             &column_type,
         );
@@ -209,7 +241,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_date"),
-                ScyllaDBTypeInfo::DateArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Date),
                 &raw_value,
                 &column_type,
             );
@@ -321,7 +353,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_date"),
-                ScyllaDBTypeInfo::DateArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Date),
                 &raw_value,
                 &column_type,
             );
@@ -337,4 +369,126 @@ mod tests {
             Ok(())
         }
     }
+
+    #[cfg(feature = "jiff-02")]
+    mod jiff {
+        use std::{rc::Rc, sync::Arc};
+
+        use jiff_02::civil::Date;
+        use scylla::{
+            cluster::metadata::{CollectionType, ColumnType, NativeType},
+            value::CqlDate,
+        };
+
+        use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+            types::{date::jiff::to_cql_date, serialize_value},
+        };
+
+        #[test]
+        fn it_can_encode_jiff_date() -> Result<(), BoxDynError> {
+            let mut buf = ScyllaDBArgumentBuffer::default();
+
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(Date::new(2025, 8, 31)?, &mut buf)?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                [Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                &[Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                vec![Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Rc::new(vec![Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?]),
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Arc::new(vec![Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?]),
+                &mut buf,
+            )?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_convert_jiff_date() -> Result<(), BoxDynError> {
+            assert_eq!(to_cql_date(&Date::new(1970, 1, 1)?), CqlDate(1 << 31));
+            assert_eq!(
+                to_cql_date(&Date::new(1969, 12, 31)?),
+                CqlDate((1 << 31) - 1)
+            );
+            assert_eq!(
+                to_cql_date(&Date::new(2025, 8, 31)?),
+                CqlDate((1 << 31) + 20331)
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_date() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Date);
+            let raw_value = serialize_value(&to_cql_date(&Date::new(2025, 8, 31)?), &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_date"),
+                ScyllaDBTypeInfo::Date,
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Date = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, Date::new(2025, 8, 31)?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_rejects_out_of_range_jiff_date() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Date);
+            let raw_value = serialize_value(&CqlDate(0), &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_date"),
+                ScyllaDBTypeInfo::Date,
+                &raw_value,
+                &column_type,
+            );
+            let result: Result<Date, _> = <_ as Decode<'_, ScyllaDB>>::decode(value);
+            assert!(result.is_err());
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_date_array() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Date))),
+            };
+            let raw_value = serialize_value(
+                &vec![
+                    to_cql_date(&Date::new(2025, 8, 31)?),
+                    to_cql_date(&Date::new(1969, 12, 31)?),
+                ],
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_date"),
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Date),
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Vec<Date> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, [Date::new(2025, 8, 31)?, Date::new(1969, 12, 31)?]);
+
+            Ok(())
+        }
+    }
 }
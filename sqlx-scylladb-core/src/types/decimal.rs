@@ -8,11 +8,76 @@ pub mod bigdecimal {
 
     impl_array_type!(
         bigdecimal_04::BigDecimal,
-        crate::ScyllaDBTypeInfo::DecimalArray,
+        crate::ScyllaDBTypeInfo::Decimal,
         crate::ScyllaDBArgument::BigDecimalArray
     );
 }
 
+#[cfg(feature = "rust_decimal-1")]
+pub mod rust_decimal {
+    use rust_decimal_1::Decimal;
+    use scylla::value::CqlDecimal;
+
+    use crate::ScyllaDBError;
+
+    impl_converted_type!(
+        Decimal,
+        CqlDecimal,
+        crate::ScyllaDBTypeInfo::Decimal,
+        crate::ScyllaDBArgument::RustDecimal,
+        crate::ScyllaDBArgument::RustDecimalArray,
+        from_cql_decimal
+    );
+
+    /// Convert a [Decimal] to a [CqlDecimal] with the shortest two's complement mantissa.
+    pub(crate) fn to_cql_decimal(decimal: &Decimal) -> CqlDecimal {
+        let bytes = decimal.mantissa().to_be_bytes();
+        let mut start = 0;
+        while start < bytes.len() - 1
+            && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+                || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+        {
+            start += 1;
+        }
+
+        CqlDecimal::from_signed_be_bytes_slice_and_exponent(&bytes[start..], decimal.scale() as i32)
+    }
+
+    /// Convert a [CqlDecimal] to a [Decimal], failing when the value does not fit.
+    pub(crate) fn from_cql_decimal(decimal: &CqlDecimal) -> Result<Decimal, ScyllaDBError> {
+        let (bytes, scale) = decimal.as_signed_be_bytes_slice_and_exponent();
+        let overflow = || {
+            ScyllaDBError::DecimalConversionError(format!(
+                "The decimal with mantissa {bytes:02x?} and scale {scale} does not fit in rust_decimal::Decimal."
+            ))
+        };
+
+        if bytes.len() > 16 {
+            return Err(overflow());
+        }
+        let fill = if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+            0xff
+        } else {
+            0x00
+        };
+        let mut buf = [fill; 16];
+        buf[16 - bytes.len()..].copy_from_slice(bytes);
+        let mut mantissa = i128::from_be_bytes(buf);
+
+        let scale = if scale < 0 {
+            mantissa = 10i128
+                .checked_pow(scale.unsigned_abs())
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or_else(overflow)?;
+            0
+        } else {
+            scale as u32
+        };
+
+        Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| overflow())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "bigdecimal-04")]
@@ -106,7 +171,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_decimal"),
-                ScyllaDBTypeInfo::DecimalArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Decimal),
                 &raw_value,
                 &column_type,
             );
@@ -122,4 +187,160 @@ mod tests {
             Ok(())
         }
     }
+
+    #[cfg(feature = "rust_decimal-1")]
+    mod rust_decimal {
+        use std::{rc::Rc, str::FromStr, sync::Arc};
+
+        use rust_decimal_1::Decimal;
+        use scylla::{
+            cluster::metadata::{CollectionType, ColumnType, NativeType},
+            value::CqlDecimal,
+        };
+
+        use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+            types::{decimal::rust_decimal::to_cql_decimal, serialize_value},
+        };
+
+        #[test]
+        fn it_can_encode_rust_decimal() -> Result<(), BoxDynError> {
+            let mut buf = ScyllaDBArgumentBuffer::default();
+
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(Decimal::from_str("123.45")?, &mut buf)?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                [Decimal::from_str("123.45")?, Decimal::from_str("-0.5")?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                &[Decimal::from_str("123.45")?, Decimal::from_str("-0.5")?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                vec![Decimal::from_str("123.45")?, Decimal::from_str("-0.5")?],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Rc::new(vec![
+                    Decimal::from_str("123.45")?,
+                    Decimal::from_str("-0.5")?,
+                ]),
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Arc::new(vec![
+                    Decimal::from_str("123.45")?,
+                    Decimal::from_str("-0.5")?,
+                ]),
+                &mut buf,
+            )?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_convert_rust_decimal_to_cql_decimal() -> Result<(), BoxDynError> {
+            assert_eq!(
+                to_cql_decimal(&Decimal::from_str("123.45")?),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x30, 0x39], 2)
+            );
+            assert_eq!(
+                to_cql_decimal(&Decimal::from_str("-0.5")?),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0xfb], 1)
+            );
+            assert_eq!(
+                to_cql_decimal(&Decimal::from_str("128")?),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x00, 0x80], 0)
+            );
+            assert_eq!(
+                to_cql_decimal(&Decimal::ZERO),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x00], 0)
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_rust_decimal() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Decimal);
+
+            for (decimal, expected) in [
+                (
+                    CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x30, 0x39], 2),
+                    Decimal::from_str("123.45")?,
+                ),
+                (
+                    CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0xfb], -9),
+                    Decimal::from_str("-5000000000")?,
+                ),
+                (to_cql_decimal(&Decimal::MAX), Decimal::MAX),
+            ] {
+                let raw_value = serialize_value(&decimal, &column_type)?;
+                let value = ScyllaDBValueRef::new(
+                    UStr::new("my_decimal"),
+                    ScyllaDBTypeInfo::Decimal,
+                    &raw_value,
+                    &column_type,
+                );
+                let decoded: Decimal = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+                assert_eq!(decoded, expected);
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_rejects_overflowing_rust_decimal() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Decimal);
+
+            for decimal in [
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x01; 13], 0),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x01], -30),
+                CqlDecimal::from_signed_be_bytes_slice_and_exponent(&[0x01], 29),
+            ] {
+                let raw_value = serialize_value(&decimal, &column_type)?;
+                let value = ScyllaDBValueRef::new(
+                    UStr::new("my_decimal"),
+                    ScyllaDBTypeInfo::Decimal,
+                    &raw_value,
+                    &column_type,
+                );
+                let result: Result<Decimal, _> = <_ as Decode<'_, ScyllaDB>>::decode(value);
+                assert!(result.is_err());
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_rust_decimal_array() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Decimal))),
+            };
+            let raw_value = serialize_value(
+                &vec![
+                    to_cql_decimal(&Decimal::from_str("123.45")?),
+                    to_cql_decimal(&Decimal::from_str("-0.5")?),
+                ],
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_decimal"),
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Decimal),
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Vec<Decimal> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(
+                decoded,
+                [Decimal::from_str("123.45")?, Decimal::from_str("-0.5")?]
+            );
+
+            Ok(())
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use scylla::value::CqlDuration;
 
-use crate::{ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
+use crate::{ScyllaDBError, ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
 
 impl_type!(
     CqlDuration,
@@ -10,10 +10,260 @@ impl_type!(
 
 impl_array_type!(
     CqlDuration,
-    ScyllaDBTypeInfo::DurationArray,
+    ScyllaDBTypeInfo::Duration,
     ScyllaDBArgument::DurationArray
 );
 
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_PER_DAY: i128 = 86_400 * NANOSECONDS_PER_SECOND;
+
+/// Implement the conversion to and from [CqlDuration] for a fixed-length duration type.
+macro_rules! impl_duration_type {
+    ($typ:ty, $to_nanoseconds:expr, $from_nanoseconds:expr) => {
+        impl ::sqlx_core::types::Type<$crate::ScyllaDB> for $typ {
+            fn type_info() -> $crate::ScyllaDBTypeInfo {
+                $crate::ScyllaDBTypeInfo::Duration
+            }
+        }
+
+        impl ::sqlx_core::encode::Encode<'_, $crate::ScyllaDB> for $typ {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                let to_nanoseconds: fn(&$typ) -> Option<i128> = $to_nanoseconds;
+                let nanoseconds = to_nanoseconds(self);
+                let duration = $crate::types::duration::cql_duration_from_nanoseconds(nanoseconds)?;
+                let argument = $crate::ScyllaDBArgument::Duration(duration);
+                buf.push(argument);
+
+                Ok(::sqlx_core::encode::IsNull::No)
+            }
+        }
+
+        impl ::sqlx_core::decode::Decode<'_, $crate::ScyllaDB> for $typ {
+            fn decode(
+                value: $crate::ScyllaDBValueRef<'_>,
+            ) -> Result<Self, ::sqlx_core::error::BoxDynError> {
+                let duration: ::scylla::value::CqlDuration = value.deserialize()?;
+                let nanoseconds = $crate::types::duration::cql_duration_to_nanoseconds(&duration)?;
+                let from_nanoseconds: fn(i128) -> Option<$typ> = $from_nanoseconds;
+                let val = from_nanoseconds(nanoseconds).ok_or_else(|| {
+                    $crate::ScyllaDBError::DurationConversionError(format!(
+                        "{duration:?} is out of range."
+                    ))
+                })?;
+                Ok(val)
+            }
+        }
+    };
+}
+
+impl_duration_type!(
+    std::time::Duration,
+    |duration| Some(duration.as_nanos() as i128),
+    |nanoseconds| {
+        let secs = u64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+        let nanos = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32;
+        Some(std::time::Duration::new(secs, nanos))
+    }
+);
+
+#[cfg(feature = "chrono-04")]
+pub mod chrono {
+    use super::NANOSECONDS_PER_SECOND;
+
+    impl_duration_type!(
+        chrono_04::Duration,
+        |duration| duration.num_nanoseconds().map(i128::from),
+        |nanoseconds| {
+            let secs = i64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_SECOND)).ok()?;
+            let nanos = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32;
+            chrono_04::Duration::new(secs, nanos)
+        }
+    );
+}
+
+#[cfg(feature = "time-03")]
+pub mod time {
+    use super::NANOSECONDS_PER_SECOND;
+
+    impl_duration_type!(
+        time_03::Duration,
+        |duration| Some(duration.whole_nanoseconds()),
+        |nanoseconds| {
+            let secs = i64::try_from(nanoseconds / NANOSECONDS_PER_SECOND).ok()?;
+            let nanos = (nanoseconds % NANOSECONDS_PER_SECOND) as i32;
+            Some(time_03::Duration::new(secs, nanos))
+        }
+    );
+}
+
+/// Convert a fixed-length duration to a [CqlDuration] of nanoseconds only.
+pub(crate) fn cql_duration_from_nanoseconds(
+    nanoseconds: Option<i128>,
+) -> Result<CqlDuration, ScyllaDBError> {
+    let nanoseconds = nanoseconds
+        .and_then(|nanoseconds| i64::try_from(nanoseconds).ok())
+        .ok_or_else(|| {
+            ScyllaDBError::DurationConversionError(String::from(
+                "The duration exceeds the range of nanoseconds of the duration type.",
+            ))
+        })?;
+
+    Ok(CqlDuration {
+        months: 0,
+        days: 0,
+        nanoseconds,
+    })
+}
+
+/// Convert a [CqlDuration] to nanoseconds. Months have no fixed length and are rejected.
+pub(crate) fn cql_duration_to_nanoseconds(duration: &CqlDuration) -> Result<i128, ScyllaDBError> {
+    if duration.months != 0 {
+        return Err(ScyllaDBError::DurationConversionError(format!(
+            "{duration:?} has months, which have no fixed length."
+        )));
+    }
+
+    Ok(duration.days as i128 * NANOSECONDS_PER_DAY + duration.nanoseconds as i128)
+}
+
+/// Format a [CqlDuration] as an ISO-8601 duration string such as `P1Y2M3DT4H5M6.7S`.
+///
+/// A negative duration is prefixed with `-`, as accepted by CQL.
+pub fn format_iso8601_duration(duration: &CqlDuration) -> String {
+    let negative = duration.months < 0 || duration.days < 0 || duration.nanoseconds < 0;
+    let months = duration.months.unsigned_abs();
+    let days = duration.days.unsigned_abs();
+    let nanoseconds = duration.nanoseconds.unsigned_abs();
+
+    let mut formatted = String::new();
+    if negative {
+        formatted.push('-');
+    }
+    formatted.push('P');
+    if months / 12 > 0 {
+        formatted.push_str(&format!("{}Y", months / 12));
+    }
+    if !months.is_multiple_of(12) {
+        formatted.push_str(&format!("{}M", months % 12));
+    }
+    if days > 0 {
+        formatted.push_str(&format!("{days}D"));
+    }
+
+    let seconds = nanoseconds / 1_000_000_000;
+    let fraction = nanoseconds % 1_000_000_000;
+    if nanoseconds > 0 || (months == 0 && days == 0) {
+        formatted.push('T');
+        if seconds / 3600 > 0 {
+            formatted.push_str(&format!("{}H", seconds / 3600));
+        }
+        if seconds % 3600 / 60 > 0 {
+            formatted.push_str(&format!("{}M", seconds % 3600 / 60));
+        }
+        if !seconds.is_multiple_of(60) || fraction > 0 || seconds == 0 {
+            formatted.push_str(&format!("{}", seconds % 60));
+            if fraction > 0 {
+                let fraction = format!("{fraction:09}");
+                formatted.push('.');
+                formatted.push_str(fraction.trim_end_matches('0'));
+            }
+            formatted.push('S');
+        }
+    }
+
+    formatted
+}
+
+/// Parse an ISO-8601 duration string such as `P1Y2M3DT4H5M6.7S` or `P2W` into a [CqlDuration].
+///
+/// A leading `-` negates every component.
+pub fn parse_iso8601_duration(value: &str) -> Result<CqlDuration, ScyllaDBError> {
+    let invalid =
+        || ScyllaDBError::DurationConversionError(format!("Invalid ISO-8601 duration '{value}'."));
+
+    let (negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut months: i64 = 0;
+    let mut days: i64 = 0;
+    let mut nanoseconds: i128 = 0;
+    let mut in_time = false;
+    let mut has_component = false;
+    let mut number = String::new();
+    for c in rest.chars() {
+        if c.is_ascii_digit() || (c == '.' && in_time) {
+            number.push(c);
+            continue;
+        }
+        if c == 'T' && !in_time && number.is_empty() {
+            in_time = true;
+            continue;
+        }
+        if number.is_empty() {
+            return Err(invalid());
+        }
+
+        match (in_time, c) {
+            (false, 'Y') => months += parse_integer(&number).ok_or_else(invalid)? * 12,
+            (false, 'M') => months += parse_integer(&number).ok_or_else(invalid)?,
+            (false, 'W') => days += parse_integer(&number).ok_or_else(invalid)? * 7,
+            (false, 'D') => days += parse_integer(&number).ok_or_else(invalid)?,
+            (true, 'H') => {
+                nanoseconds += parse_integer(&number).ok_or_else(invalid)? as i128
+                    * 3600
+                    * NANOSECONDS_PER_SECOND
+            }
+            (true, 'M') => {
+                nanoseconds += parse_integer(&number).ok_or_else(invalid)? as i128
+                    * 60
+                    * NANOSECONDS_PER_SECOND
+            }
+            (true, 'S') => nanoseconds += parse_seconds(&number).ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        }
+        has_component = true;
+        number.clear();
+    }
+    if !number.is_empty() || !has_component || rest.ends_with('T') {
+        return Err(invalid());
+    }
+
+    let sign = if negative { -1 } else { 1 };
+    Ok(CqlDuration {
+        months: i32::try_from(months * sign).map_err(|_| invalid())?,
+        days: i32::try_from(days * sign).map_err(|_| invalid())?,
+        nanoseconds: i64::try_from(nanoseconds * sign as i128).map_err(|_| invalid())?,
+    })
+}
+
+fn parse_integer(number: &str) -> Option<i64> {
+    if number.contains('.') {
+        return None;
+    }
+    number.parse::<u32>().ok().map(i64::from)
+}
+
+fn parse_seconds(number: &str) -> Option<i128> {
+    let (seconds, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if seconds.is_empty() || fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let seconds = i128::from(seconds.parse::<u32>().ok()?);
+    let fraction: i128 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<9}").parse().ok()?
+    };
+
+    Some(seconds * NANOSECONDS_PER_SECOND + fraction)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{rc::Rc, sync::Arc};
@@ -27,7 +277,10 @@ mod tests {
 
     use crate::{
         ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
-        types::serialize_value,
+        types::{
+            duration::{format_iso8601_duration, parse_iso8601_duration},
+            serialize_value,
+        },
     };
 
     #[test]
@@ -176,7 +429,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_duration"),
-            ScyllaDBTypeInfo::DurationArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Duration),
             &raw_value,
             &column_type,
         );
@@ -199,4 +452,212 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn it_can_encode_std_duration() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ =
+            <_ as Encode<'_, ScyllaDB>>::encode(std::time::Duration::from_millis(1500), &mut buf)?;
+        let result = <_ as Encode<'_, ScyllaDB>>::encode(std::time::Duration::MAX, &mut buf);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_std_duration() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Duration);
+        let raw_value = serialize_value(
+            &CqlDuration {
+                months: 0,
+                days: 1,
+                nanoseconds: 300000000,
+            },
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_duration"),
+            ScyllaDBTypeInfo::Duration,
+            &raw_value,
+            &column_type,
+        );
+        let decoded: std::time::Duration = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, std::time::Duration::new(86_400, 300000000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_months_and_negative_for_std_duration() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Duration);
+
+        for duration in [
+            CqlDuration {
+                months: 1,
+                days: 0,
+                nanoseconds: 0,
+            },
+            CqlDuration {
+                months: 0,
+                days: 0,
+                nanoseconds: -1,
+            },
+        ] {
+            let raw_value = serialize_value(&duration, &column_type)?;
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_duration"),
+                ScyllaDBTypeInfo::Duration,
+                &raw_value,
+                &column_type,
+            );
+            let result: Result<std::time::Duration, _> = <_ as Decode<'_, ScyllaDB>>::decode(value);
+            assert!(result.is_err());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono-04")]
+    #[test]
+    fn it_can_decode_chrono_duration() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Duration);
+        let raw_value = serialize_value(
+            &CqlDuration {
+                months: 0,
+                days: -1,
+                nanoseconds: -300000000,
+            },
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_duration"),
+            ScyllaDBTypeInfo::Duration,
+            &raw_value,
+            &column_type,
+        );
+        let decoded: chrono_04::Duration = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(
+            decoded,
+            -(chrono_04::Duration::days(1) + chrono_04::Duration::milliseconds(300))
+        );
+
+        let mut buf = ScyllaDBArgumentBuffer::default();
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(decoded, &mut buf)?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "time-03")]
+    #[test]
+    fn it_can_decode_time_duration() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Duration);
+        let raw_value = serialize_value(
+            &CqlDuration {
+                months: 0,
+                days: -1,
+                nanoseconds: -300000000,
+            },
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_duration"),
+            ScyllaDBTypeInfo::Duration,
+            &raw_value,
+            &column_type,
+        );
+        let decoded: time_03::Duration = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(
+            decoded,
+            -(time_03::Duration::days(1) + time_03::Duration::milliseconds(300))
+        );
+
+        let mut buf = ScyllaDBArgumentBuffer::default();
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(decoded, &mut buf)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_parse_iso8601_duration() -> Result<(), BoxDynError> {
+        assert_eq!(
+            parse_iso8601_duration("P1Y2M3DT4H5M6.7S")?,
+            CqlDuration {
+                months: 14,
+                days: 3,
+                nanoseconds: 14_706_700_000_000,
+            }
+        );
+        assert_eq!(
+            parse_iso8601_duration("-P2W")?,
+            CqlDuration {
+                months: 0,
+                days: -14,
+                nanoseconds: 0,
+            }
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT0.000000001S")?,
+            CqlDuration {
+                months: 0,
+                days: 0,
+                nanoseconds: 1,
+            }
+        );
+
+        for invalid in [
+            "",
+            "P",
+            "PT",
+            "1D",
+            "P1H",
+            "PT1D",
+            "P1.5D",
+            "PT1.0000000001S",
+            "P1",
+        ] {
+            assert!(parse_iso8601_duration(invalid).is_err(), "{invalid}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_format_iso8601_duration() -> Result<(), BoxDynError> {
+        assert_eq!(
+            format_iso8601_duration(&CqlDuration {
+                months: 14,
+                days: 3,
+                nanoseconds: 14_706_700_000_000,
+            }),
+            "P1Y2M3DT4H5M6.7S"
+        );
+        assert_eq!(
+            format_iso8601_duration(&CqlDuration {
+                months: 0,
+                days: -14,
+                nanoseconds: 0,
+            }),
+            "-P14D"
+        );
+        assert_eq!(
+            format_iso8601_duration(&CqlDuration {
+                months: 0,
+                days: 0,
+                nanoseconds: 0,
+            }),
+            "PT0S"
+        );
+
+        let duration = parse_iso8601_duration("-P1MT1M")?;
+        assert_eq!(
+            parse_iso8601_duration(&format_iso8601_duration(&duration))?,
+            duration
+        );
+
+        Ok(())
+    }
 }
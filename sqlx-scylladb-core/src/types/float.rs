@@ -1,20 +1,28 @@
-use crate::{ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
+use sqlx_core::{decode::Decode, error::BoxDynError};
+
+use crate::{
+    ScyllaDB, ScyllaDBError, ScyllaDBTypeInfo, ScyllaDBValueRef, arguments::ScyllaDBArgument,
+};
 
 impl_type!(f32, ScyllaDBTypeInfo::Float, ScyllaDBArgument::Float);
 
-impl_array_type!(
-    f32,
-    ScyllaDBTypeInfo::FloatArray,
-    ScyllaDBArgument::FloatArray
-);
+impl_array_type!(f32, ScyllaDBTypeInfo::Float, ScyllaDBArgument::FloatArray);
+
+/// Decode a `vector<float, N>` column, or a list of exactly N floats.
+impl<const N: usize> Decode<'_, ScyllaDB> for [f32; N] {
+    fn decode(value: ScyllaDBValueRef<'_>) -> Result<Self, BoxDynError> {
+        let val: Vec<f32> = value.deserialize()?;
+        let actual = val.len();
+        let val = val
+            .try_into()
+            .map_err(|_| ScyllaDBError::VectorDimensionError { expect: N, actual })?;
+        Ok(val)
+    }
+}
 
 impl_type!(f64, ScyllaDBTypeInfo::Double, ScyllaDBArgument::Double);
 
-impl_array_type!(
-    f64,
-    ScyllaDBTypeInfo::DoubleArray,
-    ScyllaDBArgument::DoubleArray
-);
+impl_array_type!(f64, ScyllaDBTypeInfo::Double, ScyllaDBArgument::DoubleArray);
 
 #[cfg(test)]
 mod tests {
@@ -22,10 +30,12 @@ mod tests {
 
     use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
 
-    use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+    use sqlx_core::{
+        decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr, types::Type,
+    };
 
     use crate::{
-        ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+        ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
         types::serialize_value,
     };
 
@@ -70,7 +80,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_float"),
-            ScyllaDBTypeInfo::FloatArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Float),
             &raw_value,
             &column_type,
         );
@@ -80,6 +90,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_can_decode_float_vector() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Vector {
+            typ: Box::new(ColumnType::Native(NativeType::Float)),
+            dimensions: 3,
+        };
+        let raw_value = serialize_value(
+            &ScyllaDBArgument::FloatArray(vec![0.5f32, 1.5, 2.5]),
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_vector"),
+            ScyllaDBTypeInfo::vector(ScyllaDBTypeInfo::Float, 3),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: [f32; 3] = <_ as Decode<'_, ScyllaDB>>::decode(value.clone())?;
+        assert_eq!(decoded, [0.5f32, 1.5, 2.5]);
+        let decoded: Vec<f32> = <_ as Decode<'_, ScyllaDB>>::decode(value.clone())?;
+        assert_eq!(decoded, [0.5f32, 1.5, 2.5]);
+        assert!(<[f32; 4] as Decode<'_, ScyllaDB>>::decode(value).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_mismatched_vector_dimensions() {
+        let column_type: ColumnType<'_> = ColumnType::Vector {
+            typ: Box::new(ColumnType::Native(NativeType::Float)),
+            dimensions: 3,
+        };
+
+        let result = serialize_value(&ScyllaDBArgument::FloatArray(vec![0.5f32]), &column_type);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_has_vector_compatible_type_info() {
+        let column = ScyllaDBTypeInfo::vector(ScyllaDBTypeInfo::Float, 3);
+        assert!(<Vec<f32> as Type<ScyllaDB>>::compatible(&column));
+        assert!(<[f32; 3] as Type<ScyllaDB>>::compatible(&column));
+        assert!(!<Vec<f64> as Type<ScyllaDB>>::compatible(&column));
+    }
+
     #[test]
     fn it_can_encode_double() -> Result<(), BoxDynError> {
         let mut buf = ScyllaDBArgumentBuffer::default();
@@ -121,7 +176,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_float"),
-            ScyllaDBTypeInfo::DoubleArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Double),
             &raw_value,
             &column_type,
         );
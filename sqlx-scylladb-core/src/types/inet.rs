@@ -6,7 +6,7 @@ impl_type!(IpAddr, ScyllaDBTypeInfo::Inet, ScyllaDBArgument::IpAddr);
 
 impl_array_type!(
     IpAddr,
-    ScyllaDBTypeInfo::InetArray,
+    ScyllaDBTypeInfo::Inet,
     ScyllaDBArgument::IpAddrArray
 );
 
@@ -100,7 +100,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_inet"),
-            ScyllaDBTypeInfo::InetArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Inet),
             &raw_value,
             &column_type,
         );
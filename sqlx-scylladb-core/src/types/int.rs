@@ -4,7 +4,7 @@ impl_type!(i8, ScyllaDBTypeInfo::TinyInt, ScyllaDBArgument::TinyInt);
 
 impl_array_type!(
     i8,
-    ScyllaDBTypeInfo::TinyIntArray,
+    ScyllaDBTypeInfo::TinyInt,
     ScyllaDBArgument::TinyIntArray
 );
 
@@ -12,21 +12,17 @@ impl_type!(i16, ScyllaDBTypeInfo::SmallInt, ScyllaDBArgument::SmallInt);
 
 impl_array_type!(
     i16,
-    ScyllaDBTypeInfo::SmallIntArray,
+    ScyllaDBTypeInfo::SmallInt,
     ScyllaDBArgument::SmallIntArray
 );
 
 impl_type!(i32, ScyllaDBTypeInfo::Int, ScyllaDBArgument::Int);
 
-impl_array_type!(i32, ScyllaDBTypeInfo::IntArray, ScyllaDBArgument::IntArray);
+impl_array_type!(i32, ScyllaDBTypeInfo::Int, ScyllaDBArgument::IntArray);
 
 impl_type!(i64, ScyllaDBTypeInfo::BigInt, ScyllaDBArgument::BigInt);
 
-impl_array_type!(
-    i64,
-    ScyllaDBTypeInfo::BigIntArray,
-    ScyllaDBArgument::BigIntArray
-);
+impl_array_type!(i64, ScyllaDBTypeInfo::BigInt, ScyllaDBArgument::BigIntArray);
 
 #[cfg(test)]
 mod tests {
@@ -82,7 +78,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_tinyint"),
-            ScyllaDBTypeInfo::TinyIntArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::TinyInt),
             &raw_value,
             &column_type,
         );
@@ -133,7 +129,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_smallint"),
-            ScyllaDBTypeInfo::SmallIntArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::SmallInt),
             &raw_value,
             &column_type,
         );
@@ -184,7 +180,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_int"),
-            ScyllaDBTypeInfo::IntArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Int),
             &raw_value,
             &column_type,
         );
@@ -235,7 +231,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_bigint"),
-            ScyllaDBTypeInfo::BigIntArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::BigInt),
             &raw_value,
             &column_type,
         );
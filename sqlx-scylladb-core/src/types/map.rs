@@ -1,87 +1,181 @@
-use std::net::IpAddr;
-
-use uuid::Uuid;
-
-use crate::{ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
-
-// String
-impl_map_type!(
-    String,
-    String,
-    ScyllaDBTypeInfo::TextTextMap,
-    ScyllaDBArgument::TextTextMap
-);
-
-impl_map_type!(
-    String,
-    bool,
-    ScyllaDBTypeInfo::TextBooleanMap,
-    ScyllaDBArgument::TextBooleanMap
-);
-
-impl_map_type!(
-    String,
-    i8,
-    ScyllaDBTypeInfo::TextTinyIntMap,
-    ScyllaDBArgument::TextTinyIntMap
-);
-
-impl_map_type!(
-    String,
-    i16,
-    ScyllaDBTypeInfo::TextSmallIntMap,
-    ScyllaDBArgument::TextSmallIntMap
-);
-
-impl_map_type!(
-    String,
-    i32,
-    ScyllaDBTypeInfo::TextIntMap,
-    ScyllaDBArgument::TextIntMap
-);
-
-impl_map_type!(
-    String,
-    i64,
-    ScyllaDBTypeInfo::TextBigIntMap,
-    ScyllaDBArgument::TextBigIntMap
-);
-
-impl_map_type!(
-    String,
-    f32,
-    ScyllaDBTypeInfo::TextFloatMap,
-    ScyllaDBArgument::TextFloatMap
-);
-
-impl_map_type!(
-    String,
-    f64,
-    ScyllaDBTypeInfo::TextDoubleMap,
-    ScyllaDBArgument::TextDoubleMap
-);
-
-impl_map_type!(
-    String,
-    Uuid,
-    ScyllaDBTypeInfo::TextUuidMap,
-    ScyllaDBArgument::TextUuidMap
-);
-
-impl_map_type!(
-    String,
-    IpAddr,
-    ScyllaDBTypeInfo::TextInetMap,
-    ScyllaDBArgument::TextIpAddrMap
-);
+use std::collections::{BTreeMap, HashMap};
+
+use scylla::deserialize::value::DeserializeValue;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBHasArrayType, ScyllaDBTypeInfo,
+    ScyllaDBValueRef, types::encode_elements,
+};
+
+impl<K, V, S> Type<ScyllaDB> for HashMap<K, V, S>
+where
+    K: Type<ScyllaDB>,
+    V: Type<ScyllaDB>,
+{
+    fn type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::map(K::type_info(), V::type_info())
+    }
+}
+
+impl<'q, K, V, S> Encode<'q, ScyllaDB> for HashMap<K, V, S>
+where
+    K: Encode<'q, ScyllaDB>,
+    V: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::Map(encode_entries(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, K, V, S> Decode<'r, ScyllaDB> for HashMap<K, V, S>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<K, V> Type<ScyllaDB> for BTreeMap<K, V>
+where
+    K: Type<ScyllaDB>,
+    V: Type<ScyllaDB>,
+{
+    fn type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::map(K::type_info(), V::type_info())
+    }
+}
+
+impl<'q, K, V> Encode<'q, ScyllaDB> for BTreeMap<K, V>
+where
+    K: Encode<'q, ScyllaDB>,
+    V: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::Map(encode_entries(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, K, V> Decode<'r, ScyllaDB> for BTreeMap<K, V>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<K, V, S> ScyllaDBHasArrayType for HashMap<K, V, S>
+where
+    K: Type<ScyllaDB>,
+    V: Type<ScyllaDB>,
+{
+    fn array_type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::list(Self::type_info())
+    }
+}
+
+impl<'q, K, V, S> Encode<'q, ScyllaDB> for Vec<HashMap<K, V, S>>
+where
+    HashMap<K, V, S>: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::List(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, K, V, S> Decode<'r, ScyllaDB> for Vec<HashMap<K, V, S>>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<K, V> ScyllaDBHasArrayType for BTreeMap<K, V>
+where
+    K: Type<ScyllaDB>,
+    V: Type<ScyllaDB>,
+{
+    fn array_type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::list(Self::type_info())
+    }
+}
+
+impl<'q, K, V> Encode<'q, ScyllaDB> for Vec<BTreeMap<K, V>>
+where
+    BTreeMap<K, V>: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::List(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, K, V> Decode<'r, ScyllaDB> for Vec<BTreeMap<K, V>>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+fn encode_entries<'a, 'q, K, V>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+) -> Result<Vec<(ScyllaDBArgument, ScyllaDBArgument)>, BoxDynError>
+where
+    K: Encode<'q, ScyllaDB> + 'a,
+    V: Encode<'q, ScyllaDB> + 'a,
+{
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            Ok((
+                ScyllaDBArgument::encode_element(key)?,
+                ScyllaDBArgument::encode_element(value)?,
+            ))
+        })
+        .collect()
+}
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, net::IpAddr, rc::Rc, str::FromStr, sync::Arc};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        net::IpAddr,
+        rc::Rc,
+        str::FromStr,
+        sync::Arc,
+    };
 
     use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
 
-    use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+    use sqlx_core::{
+        decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr, types::Type,
+    };
     use uuid::Uuid;
 
     use crate::{
@@ -160,7 +254,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextTextMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Text),
             &raw_value,
             &column_type,
         );
@@ -189,7 +283,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextTextMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Boolean),
             &raw_value,
             &column_type,
         );
@@ -213,7 +307,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextTinyIntMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::TinyInt),
             &raw_value,
             &column_type,
         );
@@ -239,7 +333,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextSmallIntMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::SmallInt),
             &raw_value,
             &column_type,
         );
@@ -265,7 +359,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextIntMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Int),
             &raw_value,
             &column_type,
         );
@@ -291,7 +385,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextBigIntMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::BigInt),
             &raw_value,
             &column_type,
         );
@@ -317,7 +411,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextFloatMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Float),
             &raw_value,
             &column_type,
         );
@@ -343,7 +437,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextFloatMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Double),
             &raw_value,
             &column_type,
         );
@@ -372,7 +466,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextUuidMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Uuid),
             &raw_value,
             &column_type,
         );
@@ -404,7 +498,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_hashmap"),
-            ScyllaDBTypeInfo::TextInetMap,
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Inet),
             &raw_value,
             &column_type,
         );
@@ -416,4 +510,151 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn it_has_map_type_info() {
+        assert_eq!(
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Uuid, ScyllaDBTypeInfo::Int),
+            <HashMap<Uuid, i32> as Type<ScyllaDB>>::type_info()
+        );
+        assert_eq!(
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Int, ScyllaDBTypeInfo::Blob),
+            <BTreeMap<i32, Vec<u8>> as Type<ScyllaDB>>::type_info()
+        );
+        assert!(<HashMap<String, Uuid> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Ascii, ScyllaDBTypeInfo::Timeuuid)
+        ));
+        assert!(!<HashMap<String, Uuid> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Int)
+        ));
+    }
+
+    #[test]
+    fn it_can_encode_btreemap() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            BTreeMap::from([(7i32, vec![0x01u8, 0x02])]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            BTreeMap::from([(Uuid::new_v4(), String::from("World!"))]),
+            &mut buf,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_serialize_encoded_btreemap() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Int)),
+                Box::new(ColumnType::Native(NativeType::Blob)),
+            ),
+        };
+        let map = BTreeMap::from([(7i32, vec![0x01u8, 0x02]), (3, vec![0x03])]);
+
+        let mut buf = ScyllaDBArgumentBuffer::default();
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode_by_ref(&map, &mut buf)?;
+        assert_eq!(
+            serialize_value(&map, &column_type)?,
+            serialize_value(&buf[0], &column_type)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_uuid_int_hashmap() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Uuid)),
+                Box::new(ColumnType::Native(NativeType::Int)),
+            ),
+        };
+        let key = Uuid::new_v4();
+        let raw_value = serialize_value(&HashMap::from([(key, 7i32)]), &column_type)?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_hashmap"),
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Uuid, ScyllaDBTypeInfo::Int),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: HashMap<Uuid, i32> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, HashMap::from([(key, 7i32)]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_int_blob_btreemap() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Int)),
+                Box::new(ColumnType::Native(NativeType::Blob)),
+            ),
+        };
+        let raw_value = serialize_value(
+            &BTreeMap::from([(7i32, vec![0x01u8, 0x02]), (3, vec![0x03])]),
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_btreemap"),
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Int, ScyllaDBTypeInfo::Blob),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: BTreeMap<i32, Vec<u8>> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(
+            decoded,
+            BTreeMap::from([(3i32, vec![0x03u8]), (7, vec![0x01, 0x02])])
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "jiff-02")]
+    mod jiff {
+        use std::collections::BTreeMap;
+
+        use jiff_02::civil::Date;
+        use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+        use sqlx_core::{encode::Encode, error::BoxDynError};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer,
+            types::{date::jiff::to_cql_date, serialize_value},
+        };
+
+        #[test]
+        fn it_can_encode_converted_values() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::Map(
+                    Box::new(ColumnType::Native(NativeType::Text)),
+                    Box::new(ColumnType::Native(NativeType::Date)),
+                ),
+            };
+            let date = Date::new(2025, 9, 1)?;
+            let map = BTreeMap::from([(String::from("Hello"), date)]);
+
+            let mut buf = ScyllaDBArgumentBuffer::default();
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode_by_ref(&map, &mut buf)?;
+            assert_eq!(
+                serialize_value(
+                    &BTreeMap::from([(String::from("Hello"), to_cql_date(&date))]),
+                    &column_type
+                )?,
+                serialize_value(&buf[0], &column_type)?
+            );
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,307 @@
+use std::collections::{BTreeSet, HashSet};
+
+use scylla::deserialize::value::DeserializeValue;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    ScyllaDB, ScyllaDBArgument, ScyllaDBArgumentBuffer, ScyllaDBHasArrayType, ScyllaDBTypeInfo,
+    ScyllaDBValueRef, types::encode_elements,
+};
+
+impl<T, S> Type<ScyllaDB> for HashSet<T, S>
+where
+    T: Type<ScyllaDB>,
+{
+    fn type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::set(T::type_info())
+    }
+}
+
+impl<'q, T, S> Encode<'q, ScyllaDB> for HashSet<T, S>
+where
+    T: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::Set(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T, S> Decode<'r, ScyllaDB> for HashSet<T, S>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<T> Type<ScyllaDB> for BTreeSet<T>
+where
+    T: Type<ScyllaDB>,
+{
+    fn type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::set(T::type_info())
+    }
+}
+
+impl<'q, T> Encode<'q, ScyllaDB> for BTreeSet<T>
+where
+    T: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::Set(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T> Decode<'r, ScyllaDB> for BTreeSet<T>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<T, S> ScyllaDBHasArrayType for HashSet<T, S>
+where
+    T: Type<ScyllaDB>,
+{
+    fn array_type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::list(Self::type_info())
+    }
+}
+
+impl<'q, T, S> Encode<'q, ScyllaDB> for Vec<HashSet<T, S>>
+where
+    HashSet<T, S>: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::List(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T, S> Decode<'r, ScyllaDB> for Vec<HashSet<T, S>>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+impl<T> ScyllaDBHasArrayType for BTreeSet<T>
+where
+    T: Type<ScyllaDB>,
+{
+    fn array_type_info() -> ScyllaDBTypeInfo {
+        ScyllaDBTypeInfo::list(Self::type_info())
+    }
+}
+
+impl<'q, T> Encode<'q, ScyllaDB> for Vec<BTreeSet<T>>
+where
+    BTreeSet<T>: Encode<'q, ScyllaDB>,
+{
+    fn encode_by_ref(&self, buf: &mut ScyllaDBArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let argument = ScyllaDBArgument::List(encode_elements(self)?);
+        buf.push(argument);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T> Decode<'r, ScyllaDB> for Vec<BTreeSet<T>>
+where
+    Self: DeserializeValue<'r, 'r>,
+{
+    fn decode(value: ScyllaDBValueRef<'r>) -> Result<Self, BoxDynError> {
+        let val: Self = value.deserialize()?;
+        Ok(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashSet},
+        net::IpAddr,
+        str::FromStr,
+        sync::Arc,
+    };
+
+    use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+
+    use sqlx_core::{
+        decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr, types::Type,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+        types::serialize_value,
+    };
+
+    #[test]
+    fn it_can_encode_hashset() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(HashSet::from([true, false]), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(HashSet::from([7i32, 11]), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            HashSet::from([String::from("Hello"), String::from("World!")]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(HashSet::from([Uuid::new_v4()]), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            HashSet::from([IpAddr::from_str("2001:db8::3")?]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            Arc::new(HashSet::from([String::from("Hello")])),
+            &mut buf,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_int_hashset() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Set(Box::new(ColumnType::Native(NativeType::Int))),
+        };
+        let raw_value = serialize_value(&HashSet::from([7i32, 11]), &column_type)?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_hashset"),
+            ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Int),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: HashSet<i32> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, HashSet::from([7, 11]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_encode_btreeset() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(BTreeSet::from([7i64, 11]), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            BTreeSet::from([String::from("Hello"), String::from("World!")]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            BTreeSet::from([vec![0x01u8, 0x02], vec![0x03]]),
+            &mut buf,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_text_btreeset() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Set(Box::new(ColumnType::Native(NativeType::Text))),
+        };
+        let raw_value = serialize_value(
+            &BTreeSet::from([String::from("World!"), String::from("Hello")]),
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_btreeset"),
+            ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Text),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: BTreeSet<String> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(
+            decoded,
+            BTreeSet::from([String::from("Hello"), String::from("World!")])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_has_set_type_info() {
+        assert_eq!(
+            ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Uuid),
+            <HashSet<Uuid> as Type<ScyllaDB>>::type_info()
+        );
+        assert_eq!(
+            ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Int),
+            <BTreeSet<i32> as Type<ScyllaDB>>::type_info()
+        );
+        assert_ne!(
+            <BTreeSet<i32> as Type<ScyllaDB>>::type_info(),
+            <Vec<i32> as Type<ScyllaDB>>::type_info()
+        );
+        assert!(<HashSet<String> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Ascii)
+        ));
+        assert!(!<HashSet<String> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Int)
+        ));
+        assert!(!<HashSet<String> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text)
+        ));
+        assert!(<Vec<String> as Type<ScyllaDB>>::compatible(
+            &ScyllaDBTypeInfo::set(ScyllaDBTypeInfo::Text)
+        ));
+    }
+
+    #[cfg(feature = "jiff-02")]
+    mod jiff {
+        use std::collections::BTreeSet;
+
+        use jiff_02::Timestamp;
+        use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+        use sqlx_core::{encode::Encode, error::BoxDynError};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer,
+            types::{serialize_value, timestamp::jiff::to_cql_timestamp},
+        };
+
+        #[test]
+        fn it_can_encode_converted_values() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::Set(Box::new(ColumnType::Native(NativeType::Timestamp))),
+            };
+            let set = BTreeSet::from([Timestamp::from_second(1)?, Timestamp::from_second(2)?]);
+
+            let mut buf = ScyllaDBArgumentBuffer::default();
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode_by_ref(&set, &mut buf)?;
+            let expected: Vec<_> = set.iter().map(to_cql_timestamp).collect();
+            assert_eq!(
+                serialize_value(&expected, &column_type)?,
+                serialize_value(&buf[0], &column_type)?
+            );
+
+            Ok(())
+        }
+    }
+}
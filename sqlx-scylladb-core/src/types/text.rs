@@ -40,7 +40,7 @@ macro_rules! impl_string_type {
 
         impl $crate::ScyllaDBHasArrayType for $typ {
             fn array_type_info() -> $crate::ScyllaDBTypeInfo {
-                $crate::ScyllaDBTypeInfo::TextArray
+                $crate::ScyllaDBTypeInfo::list($crate::ScyllaDBTypeInfo::Text)
             }
         }
 
@@ -169,7 +169,7 @@ pub mod secrecy {
 
     impl ScyllaDBHasArrayType for SecretString {
         fn array_type_info() -> ScyllaDBTypeInfo {
-            ScyllaDBTypeInfo::TextArray
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text)
         }
     }
 
@@ -386,7 +386,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_text"),
-            ScyllaDBTypeInfo::TextArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text),
             &raw_value,
             &column_type,
         );
@@ -409,7 +409,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_text"),
-            ScyllaDBTypeInfo::TextArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text),
             &raw_value,
             &column_type,
         );
@@ -490,7 +490,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_text"),
-                ScyllaDBTypeInfo::TextArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Text),
                 &raw_value,
                 &column_type,
             );
@@ -6,7 +6,7 @@ impl_type!(CqlTime, ScyllaDBTypeInfo::Time, ScyllaDBArgument::CqlTime);
 
 impl_array_type!(
     CqlTime,
-    ScyllaDBTypeInfo::TimeArray,
+    ScyllaDBTypeInfo::Time,
     ScyllaDBArgument::CqlTimeArray
 );
 
@@ -20,7 +20,7 @@ pub mod chrono {
 
     impl_array_type!(
         chrono_04::NaiveTime,
-        crate::ScyllaDBTypeInfo::TimeArray,
+        crate::ScyllaDBTypeInfo::Time,
         crate::ScyllaDBArgument::ChronoNaiveTimeArray
     );
 }
@@ -35,11 +35,37 @@ pub mod time {
 
     impl_array_type!(
         time_03::Time,
-        crate::ScyllaDBTypeInfo::TimeArray,
+        crate::ScyllaDBTypeInfo::Time,
         crate::ScyllaDBArgument::TimeArray
     );
 }
 
+#[cfg(feature = "jiff-02")]
+pub mod jiff {
+    use jiff_02::{SignedDuration, civil::Time};
+    use scylla::value::CqlTime;
+
+    impl_converted_type!(
+        Time,
+        CqlTime,
+        crate::ScyllaDBTypeInfo::Time,
+        crate::ScyllaDBArgument::JiffTime,
+        crate::ScyllaDBArgument::JiffTimeArray,
+        from_cql_time
+    );
+
+    /// Convert a [Time] to a [CqlTime].
+    pub(crate) fn to_cql_time(time: &Time) -> CqlTime {
+        let nanoseconds = time.duration_since(Time::midnight()).as_nanos();
+        CqlTime(nanoseconds as i64)
+    }
+
+    /// Convert a [CqlTime] to a [Time], failing when it is not within a day.
+    pub(crate) fn from_cql_time(time: &CqlTime) -> Result<Time, jiff_02::Error> {
+        Time::midnight().checked_add(SignedDuration::from_nanos(time.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{rc::Rc, sync::Arc};
@@ -104,7 +130,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_time"),
-            ScyllaDBTypeInfo::TimeArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Time),
             &raw_value,
             &column_type,
         );
@@ -209,7 +235,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_time"),
-                ScyllaDBTypeInfo::TimeArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Time),
                 &raw_value,
                 &column_type,
             );
@@ -305,7 +331,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_time"),
-                ScyllaDBTypeInfo::TimeArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Time),
                 &raw_value,
                 &column_type,
             );
@@ -318,4 +344,127 @@ mod tests {
             Ok(())
         }
     }
+
+    #[cfg(feature = "jiff-02")]
+    mod jiff {
+        use std::{rc::Rc, sync::Arc};
+
+        use jiff_02::civil::Time;
+        use scylla::{
+            cluster::metadata::{CollectionType, ColumnType, NativeType},
+            value::CqlTime,
+        };
+
+        use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+            types::{serialize_value, time::jiff::to_cql_time},
+        };
+
+        #[test]
+        fn it_can_encode_jiff_time() -> Result<(), BoxDynError> {
+            let mut buf = ScyllaDBArgumentBuffer::default();
+
+            let _ =
+                <_ as Encode<'_, ScyllaDB>>::encode(Time::new(16, 44, 34, 123_456_789)?, &mut buf)?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                [Time::new(16, 44, 34, 123_456_789)?, Time::MAX],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                &[Time::new(16, 44, 34, 123_456_789)?, Time::MAX],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                vec![Time::new(16, 44, 34, 123_456_789)?, Time::MAX],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Rc::new(vec![Time::new(16, 44, 34, 123_456_789)?, Time::MAX]),
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Arc::new(vec![Time::new(16, 44, 34, 123_456_789)?, Time::MAX]),
+                &mut buf,
+            )?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_convert_jiff_time() -> Result<(), BoxDynError> {
+            assert_eq!(to_cql_time(&Time::midnight()), CqlTime(0));
+            assert_eq!(
+                to_cql_time(&Time::new(16, 44, 34, 123_456_789)?),
+                CqlTime(60_274_123_456_789)
+            );
+            assert_eq!(to_cql_time(&Time::MAX), CqlTime(86_399_999_999_999));
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_time() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Time);
+            let raw_value = serialize_value(
+                &to_cql_time(&Time::new(16, 44, 34, 123_456_789)?),
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_time"),
+                ScyllaDBTypeInfo::Time,
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Time = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, Time::new(16, 44, 34, 123_456_789)?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_rejects_out_of_range_jiff_time() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Time);
+            let raw_value = serialize_value(&CqlTime(86_400_000_000_000), &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_time"),
+                ScyllaDBTypeInfo::Time,
+                &raw_value,
+                &column_type,
+            );
+            let result: Result<Time, _> = <_ as Decode<'_, ScyllaDB>>::decode(value);
+            assert!(result.is_err());
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_time_array() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Time))),
+            };
+            let raw_value = serialize_value(
+                &vec![
+                    to_cql_time(&Time::new(16, 44, 34, 123_456_789)?),
+                    to_cql_time(&Time::MAX),
+                ],
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_time"),
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Time),
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Vec<Time> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, [Time::new(16, 44, 34, 123_456_789)?, Time::MAX]);
+
+            Ok(())
+        }
+    }
 }
@@ -10,7 +10,7 @@ impl_type!(
 
 impl_array_type!(
     CqlTimestamp,
-    ScyllaDBTypeInfo::TimestampArray,
+    ScyllaDBTypeInfo::Timestamp,
     ScyllaDBArgument::CqlTimestampArray
 );
 
@@ -24,7 +24,7 @@ pub mod chrono {
 
     impl_array_type!(
         chrono_04::DateTime<chrono_04::Utc>,
-        crate::ScyllaDBTypeInfo::TimestampArray,
+        crate::ScyllaDBTypeInfo::Timestamp,
         crate::ScyllaDBArgument::ChronoDateTimeUTCArray
     );
 }
@@ -39,11 +39,38 @@ pub mod time {
 
     impl_array_type!(
         time_03::OffsetDateTime,
-        crate::ScyllaDBTypeInfo::TimestampArray,
+        crate::ScyllaDBTypeInfo::Timestamp,
         crate::ScyllaDBArgument::OffsetDateTimeArray
     );
 }
 
+#[cfg(feature = "jiff-02")]
+pub mod jiff {
+    use jiff_02::Timestamp;
+    use scylla::value::CqlTimestamp;
+
+    impl_converted_type!(
+        Timestamp,
+        CqlTimestamp,
+        crate::ScyllaDBTypeInfo::Timestamp,
+        crate::ScyllaDBArgument::JiffTimestamp,
+        crate::ScyllaDBArgument::JiffTimestampArray,
+        from_cql_timestamp
+    );
+
+    /// Convert a [Timestamp] to a [CqlTimestamp], truncating it to milliseconds.
+    pub(crate) fn to_cql_timestamp(timestamp: &Timestamp) -> CqlTimestamp {
+        CqlTimestamp(timestamp.as_millisecond())
+    }
+
+    /// Convert a [CqlTimestamp] to a [Timestamp], failing when it is out of range.
+    pub(crate) fn from_cql_timestamp(
+        timestamp: &CqlTimestamp,
+    ) -> Result<Timestamp, jiff_02::Error> {
+        Timestamp::from_millisecond(timestamp.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{rc::Rc, sync::Arc};
@@ -125,7 +152,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_timestamp"),
-            ScyllaDBTypeInfo::TimestampArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Timestamp),
             &raw_value,
             &column_type,
         );
@@ -235,7 +262,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_timestamp"),
-                ScyllaDBTypeInfo::TimestampArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Timestamp),
                 &raw_value,
                 &column_type,
             );
@@ -349,7 +376,7 @@ mod tests {
 
             let value = ScyllaDBValueRef::new(
                 UStr::new("my_timestamp"),
-                ScyllaDBTypeInfo::TimestampArray,
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Timestamp),
                 &raw_value,
                 &column_type,
             );
@@ -365,4 +392,152 @@ mod tests {
             Ok(())
         }
     }
+
+    #[cfg(feature = "jiff-02")]
+    mod jiff {
+        use std::{rc::Rc, sync::Arc};
+
+        use jiff_02::Timestamp;
+        use scylla::{
+            cluster::metadata::{CollectionType, ColumnType, NativeType},
+            value::CqlTimestamp,
+        };
+
+        use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+            types::{serialize_value, timestamp::jiff::to_cql_timestamp},
+        };
+
+        #[test]
+        fn it_can_encode_jiff_timestamp() -> Result<(), BoxDynError> {
+            let mut buf = ScyllaDBArgumentBuffer::default();
+
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Timestamp::from_millisecond(1756625358255)?,
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                [
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?,
+                ],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                &[
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?,
+                ],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                vec![
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?,
+                ],
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Rc::new(vec![
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?,
+                ]),
+                &mut buf,
+            )?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+                Arc::new(vec![
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?,
+                ]),
+                &mut buf,
+            )?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_convert_jiff_timestamp() -> Result<(), BoxDynError> {
+            assert_eq!(
+                to_cql_timestamp(&Timestamp::from_millisecond(1756625358255)?),
+                CqlTimestamp(1756625358255)
+            );
+            assert_eq!(
+                to_cql_timestamp(&Timestamp::from_nanosecond(1_500_999)?),
+                CqlTimestamp(1)
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_timestamp() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Timestamp);
+            let raw_value = serialize_value(
+                &to_cql_timestamp(&Timestamp::from_millisecond(1756625358255)?),
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_timestamp"),
+                ScyllaDBTypeInfo::Timestamp,
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Timestamp = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, Timestamp::from_millisecond(1756625358255)?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_rejects_out_of_range_jiff_timestamp() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Timestamp);
+            let raw_value = serialize_value(&CqlTimestamp(i64::MAX), &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_timestamp"),
+                ScyllaDBTypeInfo::Timestamp,
+                &raw_value,
+                &column_type,
+            );
+            let result: Result<Timestamp, _> = <_ as Decode<'_, ScyllaDB>>::decode(value);
+            assert!(result.is_err());
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_jiff_timestamp_array() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Timestamp))),
+            };
+            let raw_value = serialize_value(
+                &vec![
+                    to_cql_timestamp(&Timestamp::from_millisecond(1756625358255)?),
+                    to_cql_timestamp(&Timestamp::from_millisecond(-1)?),
+                ],
+                &column_type,
+            )?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_timestamp"),
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Timestamp),
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Vec<Timestamp> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(
+                decoded,
+                [
+                    Timestamp::from_millisecond(1756625358255)?,
+                    Timestamp::from_millisecond(-1)?
+                ]
+            );
+
+            Ok(())
+        }
+    }
 }
@@ -7,9 +7,8 @@ macro_rules! impl_tuple {
         where $($typs: ::sqlx_core::types::Type<$crate::ScyllaDB>),* {
             fn type_info() -> $crate::ScyllaDBTypeInfo {
                 let type_infos = vec![$($typs::type_info()),*];
-                let type_name = $crate::type_info::tuple_type_name(&type_infos);
 
-                $crate::ScyllaDBTypeInfo::Tuple(type_name)
+                $crate::ScyllaDBTypeInfo::tuple(type_infos)
             }
         }
 
@@ -35,6 +34,38 @@ macro_rules! impl_tuple {
                 Ok(val)
             }
         }
+
+        impl <$($typs),*> $crate::ScyllaDBHasArrayType for ($($typs,)*)
+        where $($typs: ::sqlx_core::types::Type<$crate::ScyllaDB>),* {
+            fn array_type_info() -> $crate::ScyllaDBTypeInfo {
+                let type_info = <Self as ::sqlx_core::types::Type<$crate::ScyllaDB>>::type_info();
+
+                $crate::ScyllaDBTypeInfo::list(type_info)
+            }
+        }
+
+        impl<'q, $($typs), *> ::sqlx_core::encode::Encode<'q, $crate::ScyllaDB> for ::std::vec::Vec<($($typs,)*)>
+        where ($($typs,)*): ::sqlx_core::encode::Encode<'q, $crate::ScyllaDB> {
+            fn encode_by_ref(
+                &self,
+                buf: &mut $crate::ScyllaDBArgumentBuffer,
+            ) -> Result<::sqlx_core::encode::IsNull, ::sqlx_core::error::BoxDynError> {
+                let argument = $crate::ScyllaDBArgument::List($crate::types::encode_elements(self)?);
+                buf.push(argument);
+
+                Ok(::sqlx_core::encode::IsNull::No)
+            }
+        }
+
+        impl<$($typs),*> ::sqlx_core::decode::Decode<'_, $crate::ScyllaDB> for ::std::vec::Vec<($($typs,)*)>
+        where $($typs: for<'a> ::scylla::deserialize::value::DeserializeValue<'a, 'a>),* {
+            fn decode(
+                value: $crate::ScyllaDBValueRef<'_>,
+            ) -> Result<Self, ::sqlx_core::error::BoxDynError> {
+                let val: ::std::vec::Vec<($($typs,)*)> = value.deserialize()?;
+                Ok(val)
+            }
+        }
     };
 }
 
@@ -5,11 +5,7 @@ use crate::{ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
 
 impl_type!(Uuid, ScyllaDBTypeInfo::Uuid, ScyllaDBArgument::Uuid);
 
-impl_array_type!(
-    Uuid,
-    ScyllaDBTypeInfo::UuidArray,
-    ScyllaDBArgument::UuidArray
-);
+impl_array_type!(Uuid, ScyllaDBTypeInfo::Uuid, ScyllaDBArgument::UuidArray);
 
 impl_type!(
     CqlTimeuuid,
@@ -19,7 +15,7 @@ impl_type!(
 
 impl_array_type!(
     CqlTimeuuid,
-    ScyllaDBTypeInfo::TimeuuidArray,
+    ScyllaDBTypeInfo::Timeuuid,
     ScyllaDBArgument::TimeuuidArray
 );
 
@@ -94,7 +90,7 @@ mod tests {
 
         let value = ScyllaDBValueRef::new(
             UStr::new("my_uuid"),
-            ScyllaDBTypeInfo::FloatArray,
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Float),
             &raw_value,
             &column_type,
         );
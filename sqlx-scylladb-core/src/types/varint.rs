@@ -0,0 +1,212 @@
+use scylla::value::CqlVarint;
+
+use crate::{ScyllaDBTypeInfo, arguments::ScyllaDBArgument};
+
+impl_type!(
+    CqlVarint,
+    ScyllaDBTypeInfo::Variant,
+    ScyllaDBArgument::Varint
+);
+
+impl_array_type!(
+    CqlVarint,
+    ScyllaDBTypeInfo::Variant,
+    ScyllaDBArgument::VarintArray
+);
+
+#[cfg(feature = "num-bigint-04")]
+pub mod num_bigint {
+    impl_type!(
+        num_bigint_04::BigInt,
+        crate::ScyllaDBTypeInfo::Variant,
+        crate::ScyllaDBArgument::NumBigInt
+    );
+
+    impl_array_type!(
+        num_bigint_04::BigInt,
+        crate::ScyllaDBTypeInfo::Variant,
+        crate::ScyllaDBArgument::NumBigIntArray
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, rc::Rc, sync::Arc};
+
+    use scylla::{
+        cluster::metadata::{CollectionType, ColumnType, NativeType},
+        value::CqlVarint,
+    };
+
+    use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+    use crate::{
+        ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+        types::serialize_value,
+    };
+
+    fn varint(bytes: &[u8]) -> CqlVarint {
+        CqlVarint::from_signed_bytes_be_slice(bytes)
+    }
+
+    #[test]
+    fn it_can_encode_varint() -> Result<(), BoxDynError> {
+        let mut buf = ScyllaDBArgumentBuffer::default();
+
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(varint(&[0x01, 0x00]), &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode([varint(&[0x01]), varint(&[0xff])], &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(&[varint(&[0x01]), varint(&[0xff])], &mut buf)?;
+        let _ =
+            <_ as Encode<'_, ScyllaDB>>::encode(vec![varint(&[0x01]), varint(&[0xff])], &mut buf)?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            Rc::new(vec![varint(&[0x01]), varint(&[0xff])]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            Arc::new(vec![varint(&[0x01]), varint(&[0xff])]),
+            &mut buf,
+        )?;
+        let _ = <_ as Encode<'_, ScyllaDB>>::encode(
+            HashMap::from([(String::from("Hello"), varint(&[0x01]))]),
+            &mut buf,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_varint() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Varint);
+        let raw_value = serialize_value(&varint(&[0x01, 0x00]), &column_type)?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_varint"),
+            ScyllaDBTypeInfo::Variant,
+            &raw_value,
+            &column_type,
+        );
+        let decoded: CqlVarint = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, varint(&[0x01, 0x00]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_varint_array() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Varint))),
+        };
+        let raw_value = serialize_value(&vec![varint(&[0x01]), varint(&[0xff])], &column_type)?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_varint"),
+            ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Variant),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: Vec<CqlVarint> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(decoded, [varint(&[0x01]), varint(&[0xff])]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_decode_text_varint_hashmap() -> Result<(), BoxDynError> {
+        let column_type: ColumnType<'_> = ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Text)),
+                Box::new(ColumnType::Native(NativeType::Varint)),
+            ),
+        };
+        let raw_value = serialize_value(
+            &HashMap::from([(String::from("Hello"), varint(&[0x01]))]),
+            &column_type,
+        )?;
+
+        let value = ScyllaDBValueRef::new(
+            UStr::new("my_text_varint"),
+            ScyllaDBTypeInfo::map(ScyllaDBTypeInfo::Text, ScyllaDBTypeInfo::Variant),
+            &raw_value,
+            &column_type,
+        );
+        let decoded: HashMap<String, CqlVarint> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+        assert_eq!(
+            decoded,
+            HashMap::from([(String::from("Hello"), varint(&[0x01]))])
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "num-bigint-04")]
+    mod num_bigint {
+        use std::str::FromStr;
+
+        use num_bigint_04::BigInt;
+        use scylla::cluster::metadata::{CollectionType, ColumnType, NativeType};
+
+        use sqlx_core::{decode::Decode, encode::Encode, error::BoxDynError, ext::ustr::UStr};
+
+        use crate::{
+            ScyllaDB, ScyllaDBArgumentBuffer, ScyllaDBTypeInfo, ScyllaDBValueRef,
+            types::serialize_value,
+        };
+
+        #[test]
+        fn it_can_encode_bigint() -> Result<(), BoxDynError> {
+            let mut buf = ScyllaDBArgumentBuffer::default();
+
+            let value = BigInt::from_str("123456789012345678901234567890")?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(value.clone(), &mut buf)?;
+            let _ =
+                <_ as Encode<'_, ScyllaDB>>::encode([value.clone(), BigInt::from(-1)], &mut buf)?;
+            let _ = <_ as Encode<'_, ScyllaDB>>::encode(vec![value, BigInt::from(-1)], &mut buf)?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_bigint() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Native(NativeType::Varint);
+            let expected = BigInt::from_str("-123456789012345678901234567890")?;
+            let raw_value = serialize_value(&expected, &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_varint"),
+                ScyllaDBTypeInfo::Variant,
+                &raw_value,
+                &column_type,
+            );
+            let decoded: BigInt = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, expected);
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_can_decode_bigint_array() -> Result<(), BoxDynError> {
+            let column_type: ColumnType<'_> = ColumnType::Collection {
+                frozen: false,
+                typ: CollectionType::Set(Box::new(ColumnType::Native(NativeType::Varint))),
+            };
+            let expected = vec![
+                BigInt::from(-1),
+                BigInt::from_str("123456789012345678901234567890")?,
+            ];
+            let raw_value = serialize_value(&expected, &column_type)?;
+
+            let value = ScyllaDBValueRef::new(
+                UStr::new("my_varint"),
+                ScyllaDBTypeInfo::list(ScyllaDBTypeInfo::Variant),
+                &raw_value,
+                &column_type,
+            );
+            let decoded: Vec<BigInt> = <_ as Decode<'_, ScyllaDB>>::decode(value)?;
+            assert_eq!(decoded, expected);
+
+            Ok(())
+        }
+    }
+}
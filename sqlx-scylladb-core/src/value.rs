@@ -102,12 +102,16 @@ impl<'r> ScyllaDBValueRef<'r> {
     {
         let val = if !self.is_null() {
             let frame_slice = FrameSlice::new(self.raw_value);
-            <_ as DeserializeValue>::deserialize(self.column_type, Some(frame_slice))?
+            <_ as DeserializeValue>::deserialize(self.column_type, Some(frame_slice))
         } else {
-            <_ as DeserializeValue>::deserialize(self.column_type, None)?
+            <_ as DeserializeValue>::deserialize(self.column_type, None)
         };
 
-        Ok(val)
+        val.map_err(|source| ScyllaDBError::ColumnDecodeError {
+            column_name: self.column_name.clone(),
+            column_type: self.column_type(),
+            source,
+        })
     }
 }
 
@@ -37,8 +37,9 @@ pub fn expand_user_defined_type(item: DeriveInput) -> syn::Result<TokenStream> {
                 use ::sqlx_scylladb::UserDefinedType as _;
 
                 let ty = Self::type_name();
-                let ty = ::sqlx_scylladb::ext::ustr::UStr::new(&format!("{}[]", ty));
-                ::sqlx_scylladb::ScyllaDBTypeInfo::UserDefinedTypeArray(ty)
+                ::sqlx_scylladb::ScyllaDBTypeInfo::list(
+                    ::sqlx_scylladb::ScyllaDBTypeInfo::UserDefinedType(ty),
+                )
             }
         }
 
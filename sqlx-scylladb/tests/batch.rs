@@ -1,4 +1,8 @@
-use sqlx_scylladb::ScyllaDBPool;
+use scylla::statement::{Consistency, batch::BatchType};
+use sqlx::{Acquire, Connection};
+use sqlx_scylladb::{
+    ScyllaDBError, ScyllaDBPool, ScyllaDBTransactionOptions, ScyllaDBTransactionReadPolicy,
+};
 
 #[sqlx::test(migrations = "tests/migrations")]
 async fn it_can_execute_transaction_with_batch_statement(pool: ScyllaDBPool) -> anyhow::Result<()> {
@@ -22,6 +26,7 @@ async fn it_can_execute_transaction_with_batch_statement(pool: ScyllaDBPool) ->
         .execute(&mut *tx)
         .await?;
 
+    // With the default read policy, reads are sent immediately and do not see the buffered writes.
     let row =
         sqlx::query_as::<_, (i64, String)>("SELECT my_id, my_name FROM my_tests WHERE my_id = ?")
             .bind(1i64)
@@ -45,3 +50,281 @@ async fn it_can_execute_transaction_with_batch_statement(pool: ScyllaDBPool) ->
 
     Ok(())
 }
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_begin_unlogged_batch(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut tx = pool
+        .begin_with("BEGIN UNLOGGED BATCH USING TIMESTAMP 1234")
+        .await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    let (my_name, writetime): (String, i64) =
+        sqlx::query_as("SELECT my_name, WRITETIME(my_name) FROM my_tests WHERE my_id = ?")
+            .bind(1i64)
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!("Alice", my_name);
+    assert_eq!(1234, writetime);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_cannot_begin_with_invalid_batch(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let error = pool
+        .begin_with("BEGIN BATCH USING TTL 10")
+        .await
+        .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error.without_context(),
+        ScyllaDBError::InvalidBeginStatement(_)
+    ));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_begin_with_options(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let options = ScyllaDBTransactionOptions::new()
+        .batch_type(BatchType::Unlogged)
+        .consistency(Consistency::One)
+        .timestamp(5678);
+    let mut tx = conn.begin_with_options(options).await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    let (writetime,): (i64,) =
+        sqlx::query_as("SELECT WRITETIME(my_name) FROM my_tests WHERE my_id = ?")
+            .bind(1i64)
+            .fetch_one(&mut *conn)
+            .await?;
+
+    assert_eq!(5678, writetime);
+
+    let mut tx = conn.begin().await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(2i64)
+        .bind("Bob")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    let (writetime,): (i64,) =
+        sqlx::query_as("SELECT WRITETIME(my_name) FROM my_tests WHERE my_id = ?")
+            .bind(2i64)
+            .fetch_one(&mut *conn)
+            .await?;
+
+    assert_ne!(5678, writetime);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_cannot_exceed_max_statements(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let options = ScyllaDBTransactionOptions::new().max_statements(2);
+    let mut tx = conn.begin_with_options(options).await?;
+
+    for my_id in 1..=2i64 {
+        let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+            .bind(my_id)
+            .bind("Alice")
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let error = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(3i64)
+        .bind("Alice")
+        .execute(&mut *tx)
+        .await
+        .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error.without_context(),
+        ScyllaDBError::BatchTooLarge { statements: 3, .. }
+    ));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_split_unlogged_batch_by_token(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let options = ScyllaDBTransactionOptions::new()
+        .batch_type(BatchType::Unlogged)
+        .max_statements(2)
+        .max_bytes(64)
+        .split_by_token();
+    let mut tx = conn.begin_with_options(options).await?;
+
+    for my_id in 1..=10i64 {
+        let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+            .bind(my_id)
+            .bind("Alice")
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM my_tests")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    assert_eq!(10, count);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_cannot_begin_nested_transaction_with_other_batch(
+    pool: ScyllaDBPool,
+) -> anyhow::Result<()> {
+    let mut tx = pool.begin().await?;
+
+    let error = tx
+        .begin_with("BEGIN COUNTER BATCH USING TIMESTAMP 5")
+        .await
+        .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error.without_context(),
+        ScyllaDBError::NestedTransactionOptions
+    ));
+
+    let nested = tx.begin_with("BEGIN BATCH").await?;
+    nested.commit().await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_rollback_nested_transaction(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let mut tx = conn.begin().await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&mut *tx)
+        .await?;
+
+    {
+        let mut nested = tx.begin().await?;
+
+        let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+            .bind(2i64)
+            .bind("Bob")
+            .execute(&mut *nested)
+            .await?;
+
+        nested.rollback().await?;
+    }
+
+    {
+        let mut nested = tx.begin().await?;
+
+        let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+            .bind(3i64)
+            .bind("Carol")
+            .execute(&mut *nested)
+            .await?;
+
+        nested.commit().await?;
+    }
+
+    tx.commit().await?;
+
+    let rows: Vec<(i64,)> = sqlx::query_as("SELECT my_id FROM my_tests")
+        .fetch_all(&mut *conn)
+        .await?;
+    let mut my_ids = rows.into_iter().map(|(my_id,)| my_id).collect::<Vec<_>>();
+    my_ids.sort();
+
+    assert_eq!(vec![1, 3], my_ids);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_reject_reads_in_transaction(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let options =
+        ScyllaDBTransactionOptions::new().read_policy(ScyllaDBTransactionReadPolicy::Reject);
+    let mut tx = conn.begin_with_options(options).await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&mut *tx)
+        .await?;
+
+    let error =
+        sqlx::query_as::<_, (i64, String)>("SELECT my_id, my_name FROM my_tests WHERE my_id = ?")
+            .bind(1i64)
+            .fetch_optional(&mut *tx)
+            .await
+            .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error.without_context(),
+        ScyllaDBError::ReadInTransaction
+    ));
+
+    tx.commit().await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM my_tests")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    assert_eq!(1, count);
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use scylla::statement::{
+    Consistency, Statement,
+    batch::{Batch, BatchType},
+};
+use sqlx::{Executor, Row};
+use sqlx_scylladb::{ScyllaDBPool, query_statement};
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_execute_driver_statement(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut statement = Statement::new("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)");
+    statement.set_consistency(Consistency::One);
+
+    let _ = pool
+        .execute(query_statement(statement).bind(1i64).bind("Alice"))
+        .await?;
+
+    let statement = Statement::new("SELECT my_id, my_name FROM my_tests WHERE my_id = ?");
+    let row = pool
+        .fetch_one(query_statement(statement).bind(1i64))
+        .await?;
+
+    let my_id: i64 = row.try_get("my_id")?;
+    let my_name: String = row.try_get("my_name")?;
+    assert_eq!(1, my_id);
+    assert_eq!("Alice", my_name);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_execute_driver_prepared_statement(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let prepared_statement = conn
+        .session()
+        .prepare("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .await?;
+
+    let _ = conn
+        .execute(query_statement(prepared_statement).bind(1i64).bind("Alice"))
+        .await?;
+
+    let prepared_statement = conn
+        .caching_session()
+        .add_prepared_statement(&Statement::new(
+            "SELECT my_id, my_name FROM my_tests WHERE my_id = ?",
+        ))
+        .await?;
+
+    let row = conn
+        .fetch_one(query_statement(prepared_statement).bind(1i64))
+        .await?;
+
+    let my_id: i64 = row.try_get("my_id")?;
+    let my_name: String = row.try_get("my_name")?;
+    assert_eq!(1, my_id);
+    assert_eq!("Alice", my_name);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_execute_driver_batch(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut batch = Batch::new(BatchType::Unlogged);
+    batch.append_statement("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)");
+    batch.append_statement("INSERT INTO my_tests(my_id, my_name) VALUES(2, 'Bob')");
+    batch.append_statement("UPDATE my_tests SET my_name = ? WHERE my_id = ?");
+
+    let _ = pool
+        .execute(
+            query_statement(batch)
+                .bind(1i64)
+                .bind("Alice")
+                .bind("Charlie")
+                .bind(1i64),
+        )
+        .await?;
+
+    let (my_name,): (String,) = sqlx::query_as("SELECT my_name FROM my_tests WHERE my_id = ?")
+        .bind(1i64)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!("Charlie", my_name);
+
+    let (my_name,): (String,) = sqlx::query_as("SELECT my_name FROM my_tests WHERE my_id = ?")
+        .bind(2i64)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!("Bob", my_name);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_rejects_mismatched_batch_arguments(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut batch = Batch::new(BatchType::Unlogged);
+    batch.append_statement("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)");
+
+    let result = pool
+        .execute(query_statement(batch).bind(1i64).bind("Alice").bind("Bob"))
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use sqlx_scylladb::{ScyllaDBConnectOptions, ScyllaDBError, ScyllaDBPool, ScyllaDBPoolOptions};
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_get_statement_of_error(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let error = sqlx::query("SELECT my_id FROM my_tests WHERE my_name = 'Alice'")
+        .execute(&pool)
+        .await
+        .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error,
+        ScyllaDBError::StatementError { statement, .. }
+            if statement == "SELECT my_id FROM my_tests WHERE my_name = 'Alice'"
+    ));
+    assert!(!error.is_retryable());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_redact_literals_of_statement(
+    pool_options: ScyllaDBPoolOptions,
+    connect_options: ScyllaDBConnectOptions,
+) -> anyhow::Result<()> {
+    let connect_options = connect_options.redact_parameters();
+    let pool = pool_options.connect_with(connect_options).await?;
+
+    let error = sqlx::query(
+        "SELECT my_unknown FROM my_tests WHERE my_id = 42 AND my_name = 'Alice' AND my_blob = 0xCAFE AND my_uuid = f47ac10b-58cc-4372-a567-0e02b2c3d479",
+    )
+    .execute(&pool)
+    .await
+    .unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error,
+        ScyllaDBError::StatementError { statement, .. }
+            if statement == "SELECT my_unknown FROM my_tests WHERE my_id = <redacted> AND my_name = '<redacted>' AND my_blob = <redacted> AND my_uuid = <redacted>"
+    ));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_get_syntax_error(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let error = sqlx::query("SELEC 1").execute(&pool).await.unwrap_err();
+
+    let error = error
+        .as_database_error()
+        .and_then(|error| error.try_downcast_ref::<ScyllaDBError>())
+        .expect("ScyllaDBError");
+
+    assert!(matches!(
+        error.without_context(),
+        ScyllaDBError::SyntaxError(_)
+    ));
+    assert_eq!(Some(0x2000), error.code());
+    assert!(!error.is_retryable());
+
+    Ok(())
+}
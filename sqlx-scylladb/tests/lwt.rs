@@ -0,0 +1,126 @@
+use scylla::statement::batch::{Batch, BatchType};
+use sqlx::Executor;
+use sqlx_scylladb::{ScyllaDBLwtResult, ScyllaDBPool, query_statement};
+
+#[derive(Debug, PartialEq, sqlx::FromRow)]
+struct MyTest {
+    my_id: i64,
+    my_name: Option<String>,
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_decode_if_not_exists(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let result: ScyllaDBLwtResult<MyTest> =
+        sqlx::query_as("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?) IF NOT EXISTS")
+            .bind(1i64)
+            .bind("Alice")
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!(ScyllaDBLwtResult::Applied, result);
+
+    let result: ScyllaDBLwtResult<MyTest> =
+        sqlx::query_as("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?) IF NOT EXISTS")
+            .bind(1i64)
+            .bind("Bob")
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!(
+        ScyllaDBLwtResult::NotApplied(Some(MyTest {
+            my_id: 1,
+            my_name: Some(String::from("Alice")),
+        })),
+        result
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_decode_if_exists(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let result: ScyllaDBLwtResult<MyTest> =
+        sqlx::query_as("UPDATE my_tests SET my_name = ? WHERE my_id = ? IF EXISTS")
+            .bind("Bob")
+            .bind(1i64)
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!(ScyllaDBLwtResult::NotApplied(None), result);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_decode_if_condition(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&pool)
+        .await?;
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Current {
+        my_name: Option<String>,
+    }
+
+    let result: ScyllaDBLwtResult<Current> =
+        sqlx::query_as("UPDATE my_tests SET my_name = ? WHERE my_id = ? IF my_name = ?")
+            .bind("Charlie")
+            .bind(1i64)
+            .bind("Bob")
+            .fetch_one(&pool)
+            .await?;
+
+    assert!(!result.is_applied());
+    assert_eq!(
+        Some(Current {
+            my_name: Some(String::from("Alice")),
+        }),
+        result.into_current_row()
+    );
+
+    let result: ScyllaDBLwtResult<Current> =
+        sqlx::query_as("UPDATE my_tests SET my_name = ? WHERE my_id = ? IF my_name = ?")
+            .bind("Charlie")
+            .bind(1i64)
+            .bind("Alice")
+            .fetch_one(&pool)
+            .await?;
+
+    assert!(result.is_applied());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_decode_conditional_batch(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&pool)
+        .await?;
+
+    let mut batch = Batch::new(BatchType::Logged);
+    batch.append_statement("UPDATE my_tests SET my_name = ? WHERE my_id = ? IF my_name = ?");
+
+    let row = pool
+        .fetch_one(
+            query_statement(batch)
+                .bind("Charlie")
+                .bind(1i64)
+                .bind("Bob"),
+        )
+        .await?;
+
+    let result: ScyllaDBLwtResult<MyTest> = sqlx::FromRow::from_row(&row)?;
+    assert_eq!(
+        ScyllaDBLwtResult::NotApplied(Some(MyTest {
+            my_id: 1,
+            my_name: Some(String::from("Alice")),
+        })),
+        result
+    );
+
+    Ok(())
+}
@@ -46,6 +46,8 @@ async fn it_lwt_was_affected(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!(1, query_result.rows_num);
     assert_eq!(1, query_result.rows_affected);
+    assert!(query_result.is_lwt);
+    assert_eq!(Some(true), query_result.applied);
 
     let query_result = sqlx::query("UPDATE my_tests SET my_name = ? WHERE my_id = ? IF EXISTS")
         .bind("Bob")
@@ -64,6 +66,8 @@ async fn it_lwt_was_affected(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!(1, query_result.rows_num);
     assert_eq!(0, query_result.rows_affected);
+    assert!(query_result.is_lwt);
+    assert_eq!(Some(false), query_result.applied);
 
     let query_result = sqlx::query("DELETE FROM my_tests WHERE my_id = ? IF EXISTS")
         .bind(id)
@@ -99,6 +103,24 @@ async fn it_can_get_total_rows(
 
     assert_eq!(10, query_result.rows_num);
     assert_eq!(0, query_result.rows_affected);
+    assert!(query_result.pages >= 5);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_get_response_details(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let query_result = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&pool)
+        .await?;
+
+    assert!(query_result.coordinator.is_some());
+    assert!(query_result.warnings.is_empty());
+    assert_eq!(1, query_result.pages);
+    assert!(!query_result.is_lwt);
+    assert_eq!(None, query_result.applied);
 
     Ok(())
 }
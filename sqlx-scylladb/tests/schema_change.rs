@@ -0,0 +1,44 @@
+use sqlx::{Column, Executor, Row, SqlSafeStr, Statement};
+use sqlx_scylladb::ScyllaDBPool;
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_refreshes_metadata_after_alter_table(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let _ = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&mut *conn)
+        .await?;
+
+    let statement = conn
+        .prepare("SELECT * FROM my_tests".into_sql_str())
+        .await?;
+    assert!(
+        !statement
+            .columns()
+            .iter()
+            .any(|column| column.name() == "my_nickname")
+    );
+
+    conn.execute("ALTER TABLE my_tests ADD my_nickname TEXT".into_sql_str())
+        .await?;
+
+    let row = sqlx::query("SELECT * FROM my_tests")
+        .fetch_one(&mut *conn)
+        .await?;
+    let my_nickname: Option<String> = row.try_get("my_nickname")?;
+    assert_eq!(None, my_nickname);
+
+    let statement = conn
+        .prepare("SELECT * FROM my_tests".into_sql_str())
+        .await?;
+    assert!(
+        statement
+            .columns()
+            .iter()
+            .any(|column| column.name() == "my_nickname")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use scylla::statement::Statement;
+use sqlx::Executor;
+use sqlx_scylladb::{ScyllaDBPool, query_statement};
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_can_trace_query(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let statement = Statement::new("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)");
+    let query_result = conn
+        .execute(
+            query_statement(statement)
+                .bind(1i64)
+                .bind("Alice")
+                .tracing(true),
+        )
+        .await?;
+
+    let tracing_id = query_result.tracing_id.expect("tracing id");
+    let tracing_info = conn.tracing_info(&tracing_id).await?;
+    assert!(tracing_info.coordinator.is_some());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/migrations")]
+async fn it_does_not_trace_query_by_default(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let query_result = sqlx::query("INSERT INTO my_tests(my_id, my_name) VALUES(?, ?)")
+        .bind(1i64)
+        .bind("Alice")
+        .execute(&pool)
+        .await?;
+
+    assert!(query_result.tracing_id.is_none());
+
+    Ok(())
+}
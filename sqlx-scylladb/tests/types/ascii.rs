@@ -140,8 +140,8 @@ async fn describe_ascii(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("ASCII", describe.columns()[1].type_info().name());
-    assert_eq!("ASCII[]", describe.columns()[2].type_info().name());
-    assert_eq!("ASCII[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<ASCII>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<ASCII>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
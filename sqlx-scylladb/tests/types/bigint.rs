@@ -128,8 +128,8 @@ async fn describe_bigint(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("BIGINT", describe.columns()[1].type_info().name());
-    assert_eq!("BIGINT[]", describe.columns()[2].type_info().name());
-    assert_eq!("BIGINT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<BIGINT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<BIGINT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -232,8 +232,8 @@ async fn describe_blob(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("BLOB", describe.columns()[1].type_info().name());
-    assert_eq!("BLOB[]", describe.columns()[2].type_info().name());
-    assert_eq!("BLOB[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<BLOB>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<BLOB>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -127,8 +127,8 @@ async fn describe_boolean(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("BOOLEAN", describe.columns()[1].type_info().name());
-    assert_eq!("BOOLEAN[]", describe.columns()[2].type_info().name());
-    assert_eq!("BOOLEAN[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<BOOLEAN>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<BOOLEAN>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -1,6 +1,6 @@
 use scylla::value::Counter;
 use sqlx::{Acquire, Column, Executor, FromRow, SqlSafeStr, TypeInfo};
-use sqlx_scylladb::ScyllaDBPool;
+use sqlx_scylladb::{CounterDelta, ScyllaDBPool};
 use uuid::Uuid;
 
 #[sqlx::test(migrations = "tests/types/migrations")]
@@ -8,7 +8,7 @@ async fn it_can_select_counter(pool: ScyllaDBPool) -> anyhow::Result<()> {
     let id = Uuid::new_v4();
 
     let _ = sqlx::query("UPDATE counter_tests SET my_counter = my_counter + ? WHERE my_id = ?")
-        .bind(2)
+        .bind(CounterDelta(2))
         .bind(id)
         .execute(&pool)
         .await?;
@@ -40,6 +40,22 @@ async fn it_can_select_counter(pool: ScyllaDBPool) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_rejects_integers_for_counter(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let result =
+        sqlx::query("UPDATE counter_tests SET my_counter = my_counter + ? WHERE my_id = ?")
+            .bind(2i64)
+            .bind(id)
+            .execute(&pool)
+            .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "tests/types/migrations")]
 async fn describe_counter(pool: ScyllaDBPool) -> anyhow::Result<()> {
     let mut conn = pool.acquire().await?;
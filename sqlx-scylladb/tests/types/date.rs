@@ -546,6 +546,87 @@ async fn it_can_select_time_03_date_optional(pool: ScyllaDBPool) -> anyhow::Resu
     Ok(())
 }
 
+#[cfg(feature = "jiff-02")]
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_jiff_02_date(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    use jiff_02::civil::Date;
+
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query(
+        "INSERT INTO date_tests(my_id, my_date, my_date_list, my_date_set) VALUES(?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(Date::new(2025, 8, 31)?)
+    .bind([
+        Date::new(2025, 8, 31)?,
+        Date::new(2006, 1, 2)?,
+        Date::new(1994, 3, 19)?,
+        Date::new(2059, 6, 12)?,
+    ])
+    .bind([
+        Date::new(2025, 8, 31)?,
+        Date::new(2006, 1, 2)?,
+        Date::new(1994, 3, 19)?,
+        Date::new(2025, 8, 31)?,
+    ])
+    .execute(&pool)
+    .await?;
+
+    let (my_id, my_date, my_date_list, my_date_set): (Uuid, Date, Vec<Date>, Vec<Date>) =
+        sqlx::query_as(
+            "SELECT my_id, my_date, my_date_list, my_date_set FROM date_tests WHERE my_id = ?",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(Date::new(2025, 8, 31)?, my_date);
+    assert_eq!(
+        vec![
+            Date::new(2025, 8, 31)?,
+            Date::new(2006, 1, 2)?,
+            Date::new(1994, 3, 19)?,
+            Date::new(2059, 6, 12)?,
+        ],
+        my_date_list
+    );
+    assert_eq!(
+        vec![
+            Date::new(1994, 3, 19)?,
+            Date::new(2006, 1, 2)?,
+            Date::new(2025, 8, 31)?,
+        ],
+        my_date_set
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "jiff-02")]
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_rejects_out_of_range_jiff_02_date(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    use jiff_02::civil::Date;
+
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO date_tests(my_id, my_date) VALUES(?, ?)")
+        .bind(id)
+        .bind(CqlDate(0))
+        .execute(&pool)
+        .await?;
+
+    let result: Result<(Date,), _> =
+        sqlx::query_as("SELECT my_date FROM date_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "tests/types/migrations")]
 async fn describe_date(pool: ScyllaDBPool) -> anyhow::Result<()> {
     let mut conn = pool.acquire().await?;
@@ -562,8 +643,8 @@ async fn describe_date(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("DATE", describe.columns()[1].type_info().name());
-    assert_eq!("DATE[]", describe.columns()[2].type_info().name());
-    assert_eq!("DATE[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<DATE>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<DATE>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use rust_decimal_1::Decimal;
+use sqlx::{Acquire, Column, Executor, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::ScyllaDBPool;
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_rust_decimal(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let my_decimal = Decimal::from_str("-12345678901234567.8901")?;
+    let my_decimal_list = vec![
+        Decimal::from_str("123.45")?,
+        Decimal::from_str("-0.5")?,
+        Decimal::MAX,
+    ];
+
+    let _ = sqlx::query(
+        "INSERT INTO decimal_tests(my_id, my_decimal, my_decimal_list) VALUES(?, ?, ?)",
+    )
+    .bind(id)
+    .bind(my_decimal)
+    .bind(&my_decimal_list)
+    .execute(&pool)
+    .await?;
+
+    let (my_id, selected_decimal, selected_decimal_list): (Uuid, Decimal, Vec<Decimal>) =
+        sqlx::query_as(
+            "SELECT my_id, my_decimal, my_decimal_list FROM decimal_tests WHERE my_id = ?",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(my_decimal, selected_decimal);
+    assert_eq!(my_decimal.scale(), selected_decimal.scale());
+    assert_eq!(my_decimal_list, selected_decimal_list);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_rejects_overflowing_rust_decimal(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query(
+        "INSERT INTO decimal_tests(my_id, my_decimal) VALUES(?, 123456789012345678901234567890123.4)",
+    )
+    .bind(id)
+    .execute(&pool)
+    .await?;
+
+    let result: Result<(Decimal,), _> =
+        sqlx::query_as("SELECT my_decimal FROM decimal_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_decimal(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe("SELECT my_id, my_decimal, my_decimal_list FROM decimal_tests".into_sql_str())
+        .await?;
+
+    assert_eq!("my_decimal", describe.columns()[1].name());
+
+    assert_eq!("UUID", describe.columns()[0].type_info().name());
+    assert_eq!("DECIMAL", describe.columns()[1].type_info().name());
+    assert_eq!("LIST<DECIMAL>", describe.columns()[2].type_info().name());
+
+    Ok(())
+}
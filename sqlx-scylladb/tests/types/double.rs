@@ -132,8 +132,8 @@ async fn describe_double(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("DOUBLE", describe.columns()[1].type_info().name());
-    assert_eq!("DOUBLE[]", describe.columns()[2].type_info().name());
-    assert_eq!("DOUBLE[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<DOUBLE>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<DOUBLE>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -262,7 +262,7 @@ async fn describe_duration(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("DURATION", describe.columns()[1].type_info().name());
-    assert_eq!("DURATION[]", describe.columns()[2].type_info().name());
+    assert_eq!("LIST<DURATION>", describe.columns()[2].type_info().name());
 
     Ok(())
 }
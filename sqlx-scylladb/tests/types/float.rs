@@ -131,8 +131,8 @@ async fn describe_float(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("FLOAT", describe.columns()[1].type_info().name());
-    assert_eq!("FLOAT[]", describe.columns()[2].type_info().name());
-    assert_eq!("FLOAT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<FLOAT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<FLOAT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
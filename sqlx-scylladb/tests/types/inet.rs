@@ -196,8 +196,8 @@ async fn describe_inet(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("INET", describe.columns()[1].type_info().name());
-    assert_eq!("INET[]", describe.columns()[2].type_info().name());
-    assert_eq!("INET[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<INET>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<INET>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
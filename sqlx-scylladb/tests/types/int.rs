@@ -120,8 +120,8 @@ async fn describe_int(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("INT", describe.columns()[1].type_info().name());
-    assert_eq!("INT[]", describe.columns()[2].type_info().name());
-    assert_eq!("INT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<INT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<INT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
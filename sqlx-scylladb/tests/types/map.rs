@@ -0,0 +1,100 @@
+use std::collections::{BTreeMap, HashMap};
+
+use scylla::value::{CqlDate, CqlTimestamp};
+use sqlx::{Acquire, Column, Executor, FromRow, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::ScyllaDBPool;
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_map(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let uuid_text = HashMap::from([(Uuid::new_v4(), String::from("Hello!"))]);
+    let int_blob = BTreeMap::from([(3i32, vec![0x03u8]), (7, vec![0x01, 0x02])]);
+    let text_date = HashMap::from([(String::from("my_date"), CqlDate(1 << 31))]);
+    let bigint_timestamp = BTreeMap::from([(11i64, CqlTimestamp(1_700_000_000_000))]);
+
+    let _ = sqlx::query(
+        r#"
+        INSERT INTO map_tests(my_id, my_uuid_text, my_int_blob, my_text_date, my_bigint_timestamp)
+        VALUES(?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id)
+    .bind(&uuid_text)
+    .bind(&int_blob)
+    .bind(&text_date)
+    .bind(&bigint_timestamp)
+    .execute(&pool)
+    .await?;
+
+    #[derive(FromRow)]
+    struct MapTest {
+        my_id: Uuid,
+        my_uuid_text: HashMap<Uuid, String>,
+        my_int_blob: BTreeMap<i32, Vec<u8>>,
+        my_text_date: HashMap<String, CqlDate>,
+        my_bigint_timestamp: BTreeMap<i64, CqlTimestamp>,
+    }
+
+    let row: MapTest = sqlx::query_as(
+        "SELECT my_id, my_uuid_text, my_int_blob, my_text_date, my_bigint_timestamp FROM map_tests WHERE my_id = ?",
+    )
+    .bind(id)
+    .fetch_one(&pool)
+    .await?;
+
+    assert_eq!(id, row.my_id);
+    assert_eq!(uuid_text, row.my_uuid_text);
+    assert_eq!(int_blob, row.my_int_blob);
+    assert_eq!(text_date, row.my_text_date);
+    assert_eq!(bigint_timestamp, row.my_bigint_timestamp);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_map_optional(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO map_tests(my_id, my_uuid_text) VALUES(?, ?)")
+        .bind(id)
+        .bind(None::<HashMap<Uuid, String>>)
+        .execute(&pool)
+        .await?;
+
+    let (my_uuid_text,): (Option<HashMap<Uuid, String>>,) =
+        sqlx::query_as("SELECT my_uuid_text FROM map_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await?;
+
+    assert!(my_uuid_text.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_map(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe(
+            "SELECT my_id, my_uuid_text, my_int_blob, my_text_date, my_bigint_timestamp FROM map_tests"
+                .into_sql_str(),
+        )
+        .await?;
+
+    assert_eq!("my_uuid_text", describe.columns()[1].name());
+
+    assert_eq!("MAP<UUID, TEXT>", describe.columns()[1].type_info().name());
+    assert_eq!("MAP<INT, BLOB>", describe.columns()[2].type_info().name());
+    assert_eq!("MAP<TEXT, DATE>", describe.columns()[3].type_info().name());
+    assert_eq!(
+        "MAP<BIGINT, TIMESTAMP>",
+        describe.columns()[4].type_info().name()
+    );
+
+    Ok(())
+}
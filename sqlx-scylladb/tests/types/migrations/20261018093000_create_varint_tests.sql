@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE varint_tests(
+  my_id UUID PRIMARY KEY,
+  my_varint VARINT,
+  my_varint_list LIST<VARINT>,
+  my_varint_set SET<VARINT>,
+  my_text_varint MAP<TEXT, VARINT>
+)
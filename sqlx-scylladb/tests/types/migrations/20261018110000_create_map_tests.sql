@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE map_tests(
+  my_id UUID PRIMARY KEY,
+  my_uuid_text MAP<UUID, TEXT>,
+  my_int_blob MAP<INT, BLOB>,
+  my_text_date MAP<TEXT, DATE>,
+  my_bigint_timestamp MAP<BIGINT, TIMESTAMP>
+)
@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE set_tests(
+  my_id UUID PRIMARY KEY,
+  my_int_set SET<INT>,
+  my_text_set SET<TEXT>,
+  my_user_defined_type_set SET<FROZEN<my_user_defined_type>>
+)
@@ -0,0 +1,11 @@
+-- Add migration script here
+CREATE TABLE nested_collection_tests(
+  my_id UUID,
+  my_frozen_list FROZEN<LIST<INT>>,
+  my_list_list LIST<FROZEN<LIST<INT>>>,
+  my_text_list_map MAP<TEXT, FROZEN<LIST<TEXT>>>,
+  my_uuid_set_list LIST<FROZEN<SET<UUID>>>,
+  my_tuple_list LIST<FROZEN<TUPLE<INT, TEXT>>>,
+  my_user_defined_type_map MAP<TEXT, FROZEN<my_user_defined_type>>,
+  PRIMARY KEY (my_id, my_frozen_list)
+)
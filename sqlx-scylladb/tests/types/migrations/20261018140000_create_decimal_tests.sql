@@ -0,0 +1,6 @@
+-- Add migration script here
+CREATE TABLE decimal_tests(
+  my_id UUID PRIMARY KEY,
+  my_decimal DECIMAL,
+  my_decimal_list LIST<DECIMAL>
+)
@@ -0,0 +1,5 @@
+-- Add migration script here
+CREATE TABLE vector_tests(
+  my_id UUID PRIMARY KEY,
+  my_vector VECTOR<FLOAT, 3>
+)
@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sqlx::{Acquire, Column, Executor, FromRow, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::macros::UserDefinedType;
+use sqlx_scylladb::{
+    ScyllaDBPool,
+    ext::scylla::{DeserializeValue, SerializeValue},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, SerializeValue, DeserializeValue, UserDefinedType)]
+#[user_defined_type(name = "my_user_defined_type")]
+struct MyUserDefinedType {
+    my_bigint: i64,
+    my_text: String,
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_nested_collection(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let frozen_list = vec![1i32, 2, 3];
+    let list_list = vec![vec![1i32, 2], vec![], vec![3]];
+    let text_list_map = HashMap::from([
+        (
+            String::from("greetings"),
+            vec![String::from("Hello"), String::from("Bye")],
+        ),
+        (String::from("empty"), vec![]),
+    ]);
+    let uuid_set_list = vec![
+        HashSet::from([Uuid::new_v4(), Uuid::new_v4()]),
+        HashSet::from([Uuid::new_v4()]),
+    ];
+    let tuple_list = vec![(7i32, String::from("Hello")), (11, String::from("World!"))];
+    let user_defined_type_map = BTreeMap::from([(
+        String::from("first"),
+        MyUserDefinedType {
+            my_bigint: 1,
+            my_text: String::from("Hello!"),
+        },
+    )]);
+
+    let _ = sqlx::query(
+        r#"
+        INSERT INTO nested_collection_tests(my_id, my_frozen_list, my_list_list, my_text_list_map, my_uuid_set_list, my_tuple_list, my_user_defined_type_map)
+        VALUES(?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id)
+    .bind(&frozen_list)
+    .bind(&list_list)
+    .bind(&text_list_map)
+    .bind(&uuid_set_list)
+    .bind(&tuple_list)
+    .bind(&user_defined_type_map)
+    .execute(&pool)
+    .await?;
+
+    #[derive(FromRow)]
+    struct NestedCollectionTest {
+        my_id: Uuid,
+        my_frozen_list: Vec<i32>,
+        my_list_list: Vec<Vec<i32>>,
+        my_text_list_map: HashMap<String, Vec<String>>,
+        my_uuid_set_list: Vec<HashSet<Uuid>>,
+        my_tuple_list: Vec<(i32, String)>,
+        my_user_defined_type_map: BTreeMap<String, MyUserDefinedType>,
+    }
+
+    let row: NestedCollectionTest = sqlx::query_as(
+        "SELECT my_id, my_frozen_list, my_list_list, my_text_list_map, my_uuid_set_list, my_tuple_list, my_user_defined_type_map FROM nested_collection_tests WHERE my_id = ? AND my_frozen_list = ?",
+    )
+    .bind(id)
+    .bind(&frozen_list)
+    .fetch_one(&pool)
+    .await?;
+
+    assert_eq!(id, row.my_id);
+    assert_eq!(frozen_list, row.my_frozen_list);
+    assert_eq!(list_list, row.my_list_list);
+    assert_eq!(text_list_map, row.my_text_list_map);
+    assert_eq!(uuid_set_list, row.my_uuid_set_list);
+    assert_eq!(tuple_list, row.my_tuple_list);
+    assert_eq!(user_defined_type_map, row.my_user_defined_type_map);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_nested_collection(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe(
+            "SELECT my_id, my_frozen_list, my_list_list, my_text_list_map, my_uuid_set_list, my_tuple_list, my_user_defined_type_map FROM nested_collection_tests"
+                .into_sql_str(),
+        )
+        .await?;
+
+    assert_eq!("my_list_list", describe.columns()[2].name());
+
+    assert_eq!("LIST<INT>", describe.columns()[1].type_info().name());
+    assert_eq!("LIST<LIST<INT>>", describe.columns()[2].type_info().name());
+    assert_eq!(
+        "MAP<TEXT, LIST<TEXT>>",
+        describe.columns()[3].type_info().name()
+    );
+    assert_eq!("LIST<SET<UUID>>", describe.columns()[4].type_info().name());
+    assert_eq!(
+        "LIST<TUPLE<INT, TEXT>>",
+        describe.columns()[5].type_info().name()
+    );
+    assert_eq!(
+        "MAP<TEXT, my_user_defined_type>",
+        describe.columns()[6].type_info().name()
+    );
+
+    Ok(())
+}
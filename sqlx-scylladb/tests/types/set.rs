@@ -0,0 +1,143 @@
+use std::collections::{BTreeSet, HashSet};
+
+use sqlx::{Acquire, Column, Executor, FromRow, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::macros::UserDefinedType;
+use sqlx_scylladb::{
+    ScyllaDBPool,
+    ext::scylla::{DeserializeValue, SerializeValue},
+};
+use uuid::Uuid;
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SerializeValue,
+    DeserializeValue,
+    UserDefinedType,
+)]
+#[user_defined_type(name = "my_user_defined_type")]
+struct MyUserDefinedType {
+    my_bigint: i64,
+    my_text: String,
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_set(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let int_set = HashSet::from([7i32, 11, 13]);
+    let text_set = BTreeSet::from([String::from("Hello"), String::from("World!")]);
+    let user_defined_type_set = BTreeSet::from([
+        MyUserDefinedType {
+            my_bigint: 1,
+            my_text: String::from("Hello!"),
+        },
+        MyUserDefinedType {
+            my_bigint: 2,
+            my_text: String::from("Bye."),
+        },
+    ]);
+
+    let _ = sqlx::query(
+        "INSERT INTO set_tests(my_id, my_int_set, my_text_set, my_user_defined_type_set) VALUES(?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(&int_set)
+    .bind(&text_set)
+    .bind(&user_defined_type_set)
+    .execute(&pool)
+    .await?;
+
+    #[derive(FromRow)]
+    struct SetTest {
+        my_id: Uuid,
+        my_int_set: HashSet<i32>,
+        my_text_set: BTreeSet<String>,
+        my_user_defined_type_set: BTreeSet<MyUserDefinedType>,
+    }
+
+    let row: SetTest = sqlx::query_as(
+        "SELECT my_id, my_int_set, my_text_set, my_user_defined_type_set FROM set_tests WHERE my_id = ?",
+    )
+    .bind(id)
+    .fetch_one(&pool)
+    .await?;
+
+    assert_eq!(id, row.my_id);
+    assert_eq!(int_set, row.my_int_set);
+    assert_eq!(text_set, row.my_text_set);
+    assert_eq!(user_defined_type_set, row.my_user_defined_type_set);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_set_optional(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO set_tests(my_id, my_int_set) VALUES(?, ?)")
+        .bind(id)
+        .bind(None::<HashSet<i32>>)
+        .execute(&pool)
+        .await?;
+
+    let (my_int_set,): (Option<HashSet<i32>>,) =
+        sqlx::query_as("SELECT my_int_set FROM set_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await?;
+
+    assert!(my_int_set.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_cannot_select_list_as_set(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO int_tests(my_id, my_int_list) VALUES(?, ?)")
+        .bind(id)
+        .bind(vec![7i32, 11, 13])
+        .execute(&pool)
+        .await?;
+
+    let result: Result<(HashSet<i32>,), _> =
+        sqlx::query_as("SELECT my_int_list FROM int_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await;
+
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_set(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe(
+            "SELECT my_id, my_int_set, my_text_set, my_user_defined_type_set FROM set_tests"
+                .into_sql_str(),
+        )
+        .await?;
+
+    assert_eq!("my_int_set", describe.columns()[1].name());
+
+    assert_eq!("SET<INT>", describe.columns()[1].type_info().name());
+    assert_eq!("SET<TEXT>", describe.columns()[2].type_info().name());
+    assert_eq!(
+        "SET<my_user_defined_type>",
+        describe.columns()[3].type_info().name()
+    );
+
+    Ok(())
+}
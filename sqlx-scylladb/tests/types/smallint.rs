@@ -127,8 +127,8 @@ async fn describe_smallint(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("SMALLINT", describe.columns()[1].type_info().name());
-    assert_eq!("SMALLINT[]", describe.columns()[2].type_info().name());
-    assert_eq!("SMALLINT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<SMALLINT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<SMALLINT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -180,8 +180,8 @@ async fn describe_text(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("TEXT", describe.columns()[1].type_info().name());
-    assert_eq!("TEXT[]", describe.columns()[2].type_info().name());
-    assert_eq!("TEXT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<TEXT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<TEXT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -537,6 +537,63 @@ async fn it_can_select_time_03_time_optional(pool: ScyllaDBPool) -> anyhow::Resu
     Ok(())
 }
 
+#[cfg(feature = "jiff-02")]
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_jiff_02_time(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    use jiff_02::civil::Time;
+
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query(
+        "INSERT INTO time_tests(my_id, my_time, my_time_list, my_time_set) VALUES(?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(Time::new(16, 44, 34, 123_456_789)?)
+    .bind([
+        Time::new(16, 44, 34, 123_456_789)?,
+        Time::new(15, 4, 5, 0)?,
+        Time::new(15, 7, 38, 0)?,
+        Time::new(19, 54, 23, 0)?,
+    ])
+    .bind([
+        Time::new(15, 4, 5, 0)?,
+        Time::new(15, 7, 38, 0)?,
+        Time::new(16, 44, 34, 123_456_789)?,
+    ])
+    .execute(&pool)
+    .await?;
+
+    let (my_id, my_time, my_time_list, my_time_set): (Uuid, Time, Vec<Time>, Vec<Time>) =
+        sqlx::query_as(
+            "SELECT my_id, my_time, my_time_list, my_time_set FROM time_tests WHERE my_id = ?",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(Time::new(16, 44, 34, 123_456_789)?, my_time);
+    assert_eq!(
+        vec![
+            Time::new(16, 44, 34, 123_456_789)?,
+            Time::new(15, 4, 5, 0)?,
+            Time::new(15, 7, 38, 0)?,
+            Time::new(19, 54, 23, 0)?,
+        ],
+        my_time_list
+    );
+    assert_eq!(
+        vec![
+            Time::new(15, 4, 5, 0)?,
+            Time::new(15, 7, 38, 0)?,
+            Time::new(16, 44, 34, 123_456_789)?,
+        ],
+        my_time_set
+    );
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "tests/types/migrations")]
 async fn describe_time(pool: ScyllaDBPool) -> anyhow::Result<()> {
     let mut conn = pool.acquire().await?;
@@ -553,8 +610,8 @@ async fn describe_time(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("TIME", describe.columns()[1].type_info().name());
-    assert_eq!("TIME[]", describe.columns()[2].type_info().name());
-    assert_eq!("TIME[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<TIME>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<TIME>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
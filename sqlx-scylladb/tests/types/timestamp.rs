@@ -561,6 +561,64 @@ async fn it_can_select_time_03_offset_date_time_optional(pool: ScyllaDBPool) ->
     Ok(())
 }
 
+#[cfg(feature = "jiff-02")]
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_jiff_02_timestamp(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    use jiff_02::Timestamp;
+
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query(
+        "INSERT INTO timestamp_tests(my_id, my_timestamp, my_timestamp_list, my_timestamp_set) VALUES(?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(Timestamp::from_millisecond(1756626921123)?)
+    .bind([
+        Timestamp::from_millisecond(1756626948000)?,
+        Timestamp::from_millisecond(1756626953000)?,
+        Timestamp::from_millisecond(1756626963000)?,
+        Timestamp::from_millisecond(1756626968000)?,
+    ])
+    .bind([
+        Timestamp::from_millisecond(1756626948000)?,
+        Timestamp::from_millisecond(1756626953000)?,
+        Timestamp::from_millisecond(1756626963000)?,
+        Timestamp::from_millisecond(1756626948000)?,
+    ])
+    .execute(&pool)
+    .await?;
+
+    let (my_id, my_timestamp, my_timestamp_list, my_timestamp_set): (Uuid, Timestamp, Vec<Timestamp>, Vec<Timestamp>) =
+        sqlx::query_as(
+            "SELECT my_id, my_timestamp, my_timestamp_list, my_timestamp_set FROM timestamp_tests WHERE my_id = ?",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(Timestamp::from_millisecond(1756626921123)?, my_timestamp);
+    assert_eq!(
+        vec![
+            Timestamp::from_millisecond(1756626948000)?,
+            Timestamp::from_millisecond(1756626953000)?,
+            Timestamp::from_millisecond(1756626963000)?,
+            Timestamp::from_millisecond(1756626968000)?,
+        ],
+        my_timestamp_list
+    );
+    assert_eq!(
+        vec![
+            Timestamp::from_millisecond(1756626948000)?,
+            Timestamp::from_millisecond(1756626953000)?,
+            Timestamp::from_millisecond(1756626963000)?,
+        ],
+        my_timestamp_set
+    );
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "tests/types/migrations")]
 async fn describe_timestamp(pool: ScyllaDBPool) -> anyhow::Result<()> {
     let mut conn = pool.acquire().await?;
@@ -580,8 +638,8 @@ async fn describe_timestamp(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("TIMESTAMP", describe.columns()[1].type_info().name());
-    assert_eq!("TIMESTAMP[]", describe.columns()[2].type_info().name());
-    assert_eq!("TIMESTAMP[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<TIMESTAMP>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<TIMESTAMP>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
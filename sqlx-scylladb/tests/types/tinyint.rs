@@ -127,8 +127,8 @@ async fn describe_tinyint(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("TINYINT", describe.columns()[1].type_info().name());
-    assert_eq!("TINYINT[]", describe.columns()[2].type_info().name());
-    assert_eq!("TINYINT[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<TINYINT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<TINYINT>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
@@ -291,11 +291,11 @@ async fn describe_user_defined_type(pool: ScyllaDBPool) -> anyhow::Result<()> {
         describe.columns()[1].type_info().name()
     );
     assert_eq!(
-        "my_user_defined_type[]",
+        "LIST<my_user_defined_type>",
         describe.columns()[2].type_info().name()
     );
     assert_eq!(
-        "my_user_defined_type[]",
+        "SET<my_user_defined_type>",
         describe.columns()[3].type_info().name()
     );
 
@@ -206,8 +206,8 @@ async fn describe_uuid(pool: ScyllaDBPool) -> anyhow::Result<()> {
 
     assert_eq!("UUID", describe.columns()[0].type_info().name());
     assert_eq!("UUID", describe.columns()[1].type_info().name());
-    assert_eq!("UUID[]", describe.columns()[2].type_info().name());
-    assert_eq!("UUID[]", describe.columns()[3].type_info().name());
+    assert_eq!("LIST<UUID>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<UUID>", describe.columns()[3].type_info().name());
 
     Ok(())
 }
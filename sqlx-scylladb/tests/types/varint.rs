@@ -0,0 +1,134 @@
+use std::{collections::HashMap, str::FromStr};
+
+use num_bigint_04::BigInt;
+use scylla::value::CqlVarint;
+use sqlx::{Acquire, Column, Executor, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::ScyllaDBPool;
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_varint(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let my_varint = CqlVarint::from_signed_bytes_be_slice(&[0x01, 0x00]);
+    let my_varint_list = vec![
+        CqlVarint::from_signed_bytes_be_slice(&[0x0b]),
+        CqlVarint::from_signed_bytes_be_slice(&[0xff]),
+        CqlVarint::from_signed_bytes_be_slice(&[0x0b]),
+    ];
+    let my_text_varint = HashMap::from([(
+        String::from("my_varint"),
+        CqlVarint::from_signed_bytes_be_slice(&[0x07]),
+    )]);
+
+    let _ = sqlx::query(
+        "INSERT INTO varint_tests(my_id, my_varint, my_varint_list, my_varint_set, my_text_varint) VALUES(?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(&my_varint)
+    .bind(&my_varint_list)
+    .bind(&my_varint_list)
+    .bind(&my_text_varint)
+    .execute(&pool)
+    .await?;
+
+    let (my_id, selected_varint, selected_varint_list, selected_varint_set, selected_text_varint): (
+        Uuid,
+        CqlVarint,
+        Vec<CqlVarint>,
+        Vec<CqlVarint>,
+        HashMap<String, CqlVarint>,
+    ) = sqlx::query_as(
+        "SELECT my_id, my_varint, my_varint_list, my_varint_set, my_text_varint FROM varint_tests WHERE my_id = ?",
+    )
+    .bind(id)
+    .fetch_one(&pool)
+    .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(my_varint, selected_varint);
+    assert_eq!(my_varint_list, selected_varint_list);
+    assert_eq!(
+        vec![
+            CqlVarint::from_signed_bytes_be_slice(&[0xff]),
+            CqlVarint::from_signed_bytes_be_slice(&[0x0b]),
+        ],
+        selected_varint_set
+    );
+    assert_eq!(my_text_varint, selected_text_varint);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_num_bigint(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let my_varint = BigInt::from_str("-123456789012345678901234567890")?;
+    let my_varint_list = vec![BigInt::from(11), BigInt::from(-1), BigInt::from(11)];
+    let my_text_varint = HashMap::from([(
+        String::from("my_varint"),
+        BigInt::from_str("123456789012345678901234567890")?,
+    )]);
+
+    let _ = sqlx::query(
+        "INSERT INTO varint_tests(my_id, my_varint, my_varint_list, my_varint_set, my_text_varint) VALUES(?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(&my_varint)
+    .bind(&my_varint_list)
+    .bind(&my_varint_list)
+    .bind(&my_text_varint)
+    .execute(&pool)
+    .await?;
+
+    let (my_id, selected_varint, selected_varint_list, selected_varint_set, selected_text_varint): (
+        Uuid,
+        BigInt,
+        Vec<BigInt>,
+        Vec<BigInt>,
+        HashMap<String, BigInt>,
+    ) = sqlx::query_as(
+        "SELECT my_id, my_varint, my_varint_list, my_varint_set, my_text_varint FROM varint_tests WHERE my_id = ?",
+    )
+    .bind(id)
+    .fetch_one(&pool)
+    .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!(my_varint, selected_varint);
+    assert_eq!(my_varint_list, selected_varint_list);
+    assert_eq!(
+        vec![BigInt::from(-1), BigInt::from(11)],
+        selected_varint_set
+    );
+    assert_eq!(my_text_varint, selected_text_varint);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_varint(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe(
+            "SELECT my_id, my_varint, my_varint_list, my_varint_set, my_text_varint FROM varint_tests"
+                .into_sql_str(),
+        )
+        .await?;
+
+    assert_eq!("my_varint", describe.columns()[1].name());
+
+    assert_eq!("UUID", describe.columns()[0].type_info().name());
+    assert_eq!("VARIANT", describe.columns()[1].type_info().name());
+    assert_eq!("LIST<VARIANT>", describe.columns()[2].type_info().name());
+    assert_eq!("SET<VARIANT>", describe.columns()[3].type_info().name());
+    assert_eq!(
+        "MAP<TEXT, VARIANT>",
+        describe.columns()[4].type_info().name()
+    );
+
+    Ok(())
+}
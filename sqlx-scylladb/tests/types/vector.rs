@@ -0,0 +1,108 @@
+use sqlx::{Acquire, Column, Executor, FromRow, SqlSafeStr, TypeInfo};
+use sqlx_scylladb::{ScyllaDBError, ScyllaDBPool};
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_can_select_vector(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO vector_tests(my_id, my_vector) VALUES(?, ?)")
+        .bind(id)
+        .bind([0.5f32, 1.5, 2.5])
+        .execute(&pool)
+        .await?;
+
+    let (my_id, my_vector): (Uuid, [f32; 3]) =
+        sqlx::query_as("SELECT my_id, my_vector FROM vector_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!(id, my_id);
+    assert_eq!([0.5f32, 1.5, 2.5], my_vector);
+
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO vector_tests(my_id, my_vector) VALUES(?, ?)")
+        .bind(id)
+        .bind(vec![3.5f32, 4.5, 5.5])
+        .execute(&pool)
+        .await?;
+
+    #[derive(FromRow)]
+    struct VectorTest {
+        my_id: Uuid,
+        my_vector: Vec<f32>,
+    }
+
+    let row: VectorTest =
+        sqlx::query_as("SELECT my_id, my_vector FROM vector_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await?;
+
+    assert_eq!(id, row.my_id);
+    assert_eq!(vec![3.5f32, 4.5, 5.5], row.my_vector);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_cannot_bind_mismatched_vector(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let result = sqlx::query("INSERT INTO vector_tests(my_id, my_vector) VALUES(?, ?)")
+        .bind(id)
+        .bind(vec![0.5f32, 1.5])
+        .execute(&pool)
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn it_cannot_select_mismatched_vector(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let id = Uuid::new_v4();
+
+    let _ = sqlx::query("INSERT INTO vector_tests(my_id, my_vector) VALUES(?, ?)")
+        .bind(id)
+        .bind([0.5f32, 1.5, 2.5])
+        .execute(&pool)
+        .await?;
+
+    let result: Result<([f32; 4],), _> =
+        sqlx::query_as("SELECT my_vector FROM vector_tests WHERE my_id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await;
+
+    let Err(sqlx::Error::ColumnDecode { source, .. }) = result else {
+        panic!("expect a column decode error.");
+    };
+    assert!(matches!(
+        source.downcast_ref::<ScyllaDBError>(),
+        Some(ScyllaDBError::VectorDimensionError {
+            expect: 4,
+            actual: 3
+        })
+    ));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "tests/types/migrations")]
+async fn describe_vector(pool: ScyllaDBPool) -> anyhow::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let conn = conn.acquire().await?;
+
+    let describe = conn
+        .describe("SELECT my_id, my_vector FROM vector_tests".into_sql_str())
+        .await?;
+
+    assert_eq!("my_vector", describe.columns()[1].name());
+    assert_eq!("VECTOR<FLOAT, 3>", describe.columns()[1].type_info().name());
+
+    Ok(())
+}